@@ -12,7 +12,7 @@ fn generate_protocols() {
         .type_attribute("NodeAddress", "#[derive(Eq, Hash)]")
         .type_attribute(
             ".",
-            "#[cfg_attr(feature = \"dummy-seed\", derive(serde::Serialize, serde::Deserialize))]",
+            "#[cfg_attr(any(feature = \"dummy-seed\", feature = \"inspect\"), derive(serde::Serialize, serde::Deserialize))]",
         )
         .extern_path(".risq.custom", "crate::bisq::payload::custom_messages")
         .compile_protos(&protocol_files(), &protocol_includes())
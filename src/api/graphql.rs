@@ -1,11 +1,23 @@
 mod btc_offer;
 
 use crate::{
-    bisq::SequencedMessageHash,
+    bisq::{PersistentMessageHash, SequencedMessageHash},
     domain::{
+        account_age::{AccountAge, AccountAgeCache},
+        alert::{ActiveAlert, AlertCache, PrivateNotification},
+        amount::NumberWithPrecision,
         currency::{self, Currency},
+        dao::{
+            BlockStateHashes, BsqAddress, BsqBlocks, BsqSpentInfo, BsqSupply, BsqTx, BsqTxInput,
+            BsqTxOutput, BsqTxOutputType, BsqTxType, DaoBlindVote, DaoCycle, DaoProposal,
+            DaoStateHashes, GovernanceStore, ProposalKind, StateHashKind, StateHashVariant,
+            BSQ_PRECISION,
+        },
+        dispute_agent::{DisputeAgent, DisputeAgentRegistry, DisputeAgentRole, GetDisputeAgents},
+        filter::{ActiveFilter, FilterCache},
         market::{self, Market},
         offer::{message::GetOpenOffers, OfferBook, OfferDirection, OpenOffer},
+        signed_witness::{AccountSignState, AccountSigning, SignedWitnessCache},
         statistics::*,
     },
     p2p::{BootstrapState, Status},
@@ -34,7 +46,7 @@ pub fn graphql(
     request: web::Json<GraphQLRequest>,
 ) -> impl Future<Item = HttpResponse, Error = Error> {
     match status.bootstrap_state() {
-        BootstrapState::Bootstrapped => (),
+        BootstrapState::Bootstrapped { .. } => (),
         state => {
             return future::Either::A(future::ok(
                 HttpResponse::ServiceUnavailable().body(state.to_string()),
@@ -70,31 +82,92 @@ pub struct GraphQLContextWrapper {
     #[cfg(feature = "statistics")]
     pub stats_cache: StatsCache,
     pub offer_book: Addr<OfferBook>,
+    pub filter_cache: FilterCache,
+    pub alert_cache: AlertCache,
+    pub account_ages: AccountAgeCache,
+    pub signed_witnesses: SignedWitnessCache,
+    pub governance: GovernanceStore,
+    pub dao_state_hashes: DaoStateHashes,
+    pub bsq_blocks: BsqBlocks,
+    pub dispute_agents: Addr<DisputeAgentRegistry>,
 }
 impl GraphQLContextWrapper {
     #[cfg(feature = "statistics")]
     pub fn get(&self) -> impl Future<Item = GraphQLContext, Error = Error> {
-        Future::join(
+        let filter = self.filter_cache.active();
+        let alert = self.alert_cache.alert();
+        let private_notification = self.alert_cache.private_notification();
+        let account_ages = self.account_ages.clone();
+        let signed_witnesses = self.signed_witnesses.clone();
+        let governance = self.governance.clone();
+        let dao_state_hashes = self.dao_state_hashes.clone();
+        let bsq_blocks = self.bsq_blocks.clone();
+        Future::join3(
             self.stats_cache.inner().map_err(Error::from),
             self.offer_book.send(GetOpenOffers).map_err(Error::from),
+            self.dispute_agents
+                .send(GetDisputeAgents)
+                .map_err(Error::from),
+        )
+        .map(
+            |(stats_cache, open_offers, dispute_agents)| GraphQLContext {
+                stats_cache,
+                open_offers,
+                dispute_agents,
+                filter,
+                alert,
+                private_notification,
+                account_ages,
+                signed_witnesses,
+                governance,
+                dao_state_hashes,
+                bsq_blocks,
+            },
         )
-        .map(|(stats_cache, open_offers)| GraphQLContext {
-            stats_cache,
-            open_offers,
-        })
     }
     #[cfg(not(feature = "statistics"))]
     pub fn get(&self) -> impl Future<Item = GraphQLContext, Error = Error> {
-        self.offer_book
-            .send(GetOpenOffers)
-            .map_err(Error::from)
-            .map(|open_offers| GraphQLContext { open_offers })
+        let filter = self.filter_cache.active();
+        let alert = self.alert_cache.alert();
+        let private_notification = self.alert_cache.private_notification();
+        let account_ages = self.account_ages.clone();
+        let signed_witnesses = self.signed_witnesses.clone();
+        let governance = self.governance.clone();
+        let dao_state_hashes = self.dao_state_hashes.clone();
+        let bsq_blocks = self.bsq_blocks.clone();
+        Future::join(
+            self.offer_book.send(GetOpenOffers).map_err(Error::from),
+            self.dispute_agents
+                .send(GetDisputeAgents)
+                .map_err(Error::from),
+        )
+        .map(|(open_offers, dispute_agents)| GraphQLContext {
+            open_offers,
+            dispute_agents,
+            filter,
+            alert,
+            private_notification,
+            account_ages,
+            signed_witnesses,
+            governance,
+            dao_state_hashes,
+            bsq_blocks,
+        })
     }
 }
 pub struct GraphQLContext {
     #[cfg(feature = "statistics")]
     stats_cache: locks::RwLockReadGuard<StatsCacheInner>,
     open_offers: Arc<HashMap<SequencedMessageHash, OpenOffer>>,
+    dispute_agents: Arc<HashMap<SequencedMessageHash, DisputeAgent>>,
+    filter: Option<Arc<ActiveFilter>>,
+    alert: Option<Arc<ActiveAlert>>,
+    private_notification: Option<Arc<PrivateNotification>>,
+    account_ages: AccountAgeCache,
+    signed_witnesses: SignedWitnessCache,
+    governance: GovernanceStore,
+    dao_state_hashes: DaoStateHashes,
+    bsq_blocks: BsqBlocks,
 }
 impl juniper::Context for GraphQLContext {}
 
@@ -156,6 +229,135 @@ impl QueryFields for Query {
         })
     }
 
+    fn field_filter(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, ActiveFilter, juniper_from_schema::Walked>,
+    ) -> FieldResult<Option<ActiveFilter>> {
+        Ok(executor.context().filter.as_deref().cloned())
+    }
+
+    fn field_alert(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, ActiveAlert, juniper_from_schema::Walked>,
+    ) -> FieldResult<Option<ActiveAlert>> {
+        Ok(executor.context().alert.as_deref().cloned())
+    }
+
+    fn field_private_notification(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, PrivateNotification, juniper_from_schema::Walked>,
+    ) -> FieldResult<Option<PrivateNotification>> {
+        Ok(executor.context().private_notification.as_deref().cloned())
+    }
+
+    fn field_dispute_agents(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, DisputeAgent, juniper_from_schema::Walked>,
+        role: Option<AgentRole>,
+    ) -> FieldResult<Vec<DisputeAgent>> {
+        let role = role.map(DisputeAgentRole::from);
+        let mut agents: Vec<DisputeAgent> = executor
+            .context()
+            .dispute_agents
+            .values()
+            .filter(|a| !a.is_expired())
+            .filter(|a| role.is_none() || a.role == role.unwrap())
+            .cloned()
+            .collect();
+        agents.sort_unstable_by(|a, b| a.registration_date.cmp(&b.registration_date));
+        Ok(agents)
+    }
+
+    fn field_account_age(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, AccountAge, juniper_from_schema::Walked>,
+        witness_hash: String,
+    ) -> FieldResult<Option<AccountAge>> {
+        let witness_hash = ripemd160::Hash::from_hex(&witness_hash)
+            .map_err(|_| format!("Invalid witness hash '{}'", witness_hash))?;
+        Ok(executor
+            .context()
+            .account_ages
+            .get(PersistentMessageHash::new(witness_hash)))
+    }
+
+    fn field_account_signing(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, AccountSigning, juniper_from_schema::Walked>,
+        witness_hash: String,
+    ) -> FieldResult<AccountSigning> {
+        let witness_hash = ripemd160::Hash::from_hex(&witness_hash)
+            .map_err(|_| format!("Invalid witness hash '{}'", witness_hash))?;
+        Ok(executor
+            .context()
+            .signed_witnesses
+            .signing(PersistentMessageHash::new(witness_hash)))
+    }
+
+    fn field_dao_cycles(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, DaoCycle, juniper_from_schema::Walked>,
+    ) -> FieldResult<Vec<DaoCycle>> {
+        let context = executor.context();
+        Ok(context.governance.cycles(&context.bsq_blocks))
+    }
+
+    fn field_dao_state_hashes(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, BlockStateHashes, juniper_from_schema::Walked>,
+        state_type: StateHashType,
+        only_conflicts: Option<bool>,
+    ) -> FieldResult<Vec<BlockStateHashes>> {
+        let only_conflicts = only_conflicts.unwrap_or(false);
+        Ok(executor
+            .context()
+            .dao_state_hashes
+            .blocks(state_type.into())
+            .into_iter()
+            .filter(|block| !only_conflicts || block.in_conflict())
+            .collect())
+    }
+
+    fn field_bsq_tx(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, BsqTx, juniper_from_schema::Walked>,
+        id: String,
+    ) -> FieldResult<Option<BsqTx>> {
+        Ok(executor.context().bsq_blocks.tx(&id))
+    }
+
+    fn field_bsq_address(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, BsqAddress, juniper_from_schema::Walked>,
+        address: String,
+    ) -> FieldResult<Option<BsqAddress>> {
+        // Bisq displays BSQ addresses with a B prefix
+        let address = if address.starts_with('B') {
+            &address[1..]
+        } else {
+            &address
+        };
+        Ok(executor.context().bsq_blocks.address(address))
+    }
+
+    fn field_bsq_supply(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, BsqSupply, juniper_from_schema::Walked>,
+    ) -> FieldResult<BsqSupply> {
+        Ok(executor.context().bsq_blocks.supply())
+    }
+
     fn field_markets(
         &self,
         _executor: &juniper::Executor<'_, GraphQLContext>,
@@ -661,6 +863,602 @@ impl OpenOfferFields for OpenOffer {
         let btc_offer = BtcOffer::new(self);
         Ok(btc_offer.volume().format(TARGET_PRECISION))
     }
+
+    fn field_maker_account_age(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, AccountAge, juniper_from_schema::Walked>,
+    ) -> FieldResult<Option<AccountAge>> {
+        Ok(self
+            .account_age_witness_hash
+            .and_then(|hash| executor.context().account_ages.get(hash)))
+    }
+
+    fn field_maker_account_signing(
+        &self,
+        executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, AccountSigning, juniper_from_schema::Walked>,
+    ) -> FieldResult<Option<AccountSigning>> {
+        Ok(self
+            .account_age_witness_hash
+            .map(|hash| executor.context().signed_witnesses.signing(hash)))
+    }
+}
+
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+impl AccountAgeFields for AccountAge {
+    fn field_witness_hash(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        Ok(Vec::<u8>::from(self.witness_hash).to_hex())
+    }
+    fn field_witness_date(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<UnixMillis> {
+        Ok(self.witness_date.into())
+    }
+    fn field_age_days(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<i32> {
+        Ok((self.age().as_secs() / DAY_SECS) as i32)
+    }
+}
+
+impl ActiveFilterFields for ActiveFilter {
+    fn field_banned_offer_ids(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&Vec<String>> {
+        Ok(&self.banned_offer_ids)
+    }
+    fn field_banned_node_addresses(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&Vec<String>> {
+        Ok(&self.banned_node_addresses)
+    }
+    fn field_banned_currencies(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&Vec<String>> {
+        Ok(&self.banned_currencies)
+    }
+    fn field_banned_payment_methods(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&Vec<String>> {
+        Ok(&self.banned_payment_methods)
+    }
+    fn field_disable_dao(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<bool> {
+        Ok(self.disable_dao)
+    }
+    fn field_disable_trade_below_version(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&Option<String>> {
+        Ok(&self.disable_trade_below_version)
+    }
+    fn field_created_at(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<UnixMillis> {
+        Ok(self.created_at.into())
+    }
+}
+
+impl ActiveAlertFields for ActiveAlert {
+    fn field_message(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&String> {
+        Ok(&self.message)
+    }
+    fn field_version(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&String> {
+        Ok(&self.version)
+    }
+    fn field_is_update_info(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<bool> {
+        Ok(self.is_update_info)
+    }
+    fn field_created_at(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<UnixMillis> {
+        Ok(self.created_at.into())
+    }
+}
+
+impl PrivateNotificationFields for PrivateNotification {
+    fn field_message(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&String> {
+        Ok(&self.message)
+    }
+    fn field_sender(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<String>> {
+        Ok(self.sender.as_ref().map(|addr| addr.to_string()))
+    }
+    fn field_received_at(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<UnixMillis> {
+        Ok(self.received_at.into())
+    }
+}
+
+impl AccountSigningFields for AccountSigning {
+    fn field_witness_hash(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        Ok(Vec::<u8>::from(self.witness_hash).to_hex())
+    }
+    fn field_sign_state(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<SignState> {
+        Ok(self.state.into())
+    }
+    fn field_signed(&self, _executor: &juniper::Executor<'_, GraphQLContext>) -> FieldResult<bool> {
+        Ok(self.state != AccountSignState::Unsigned)
+    }
+    fn field_signed_at(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<UnixMillis>> {
+        Ok(self.signed_at.map(UnixMillis::from))
+    }
+}
+
+impl DaoCycleFields for DaoCycle {
+    fn field_number(&self, _executor: &juniper::Executor<'_, GraphQLContext>) -> FieldResult<i32> {
+        Ok(self.number as i32)
+    }
+    fn field_first_block_height(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<i32> {
+        Ok(self.first_block_height as i32)
+    }
+    fn field_proposals(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, DaoProposal, juniper_from_schema::Walked>,
+    ) -> FieldResult<&Vec<DaoProposal>> {
+        Ok(&self.proposals)
+    }
+    fn field_blind_votes(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, DaoBlindVote, juniper_from_schema::Walked>,
+    ) -> FieldResult<&Vec<DaoBlindVote>> {
+        Ok(&self.blind_votes)
+    }
+    fn field_formatted_total_stake(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        let mut total = NumberWithPrecision::new(0, BSQ_PRECISION);
+        self.blind_votes.iter().for_each(|vote| total += vote.stake);
+        Ok(total.format(BSQ_PRECISION))
+    }
+}
+
+impl DaoProposalFields for DaoProposal {
+    fn field_tx_id(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&String> {
+        Ok(&self.tx_id)
+    }
+    fn field_name(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&String> {
+        Ok(&self.name)
+    }
+    fn field_link(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&String> {
+        Ok(&self.link)
+    }
+    fn field_proposal_type(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<ProposalType> {
+        Ok((&self.kind).into())
+    }
+    fn field_created_at(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<UnixMillis> {
+        Ok(self.created_at.into())
+    }
+    fn field_is_temp(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<bool> {
+        Ok(self.is_temp)
+    }
+    fn field_formatted_requested_bsq(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<String>> {
+        Ok(match &self.kind {
+            ProposalKind::Compensation { requested_bsq, .. }
+            | ProposalKind::Reimbursement { requested_bsq, .. } => {
+                Some(requested_bsq.format(BSQ_PRECISION))
+            }
+            _ => None,
+        })
+    }
+    fn field_bsq_address(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<String>> {
+        Ok(match &self.kind {
+            ProposalKind::Compensation { bsq_address, .. }
+            | ProposalKind::Reimbursement { bsq_address, .. } => Some(bsq_address.clone()),
+            _ => None,
+        })
+    }
+    fn field_param(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<String>> {
+        Ok(match &self.kind {
+            ProposalKind::ChangeParam { param, .. } => Some(param.clone()),
+            _ => None,
+        })
+    }
+    fn field_param_value(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<String>> {
+        Ok(match &self.kind {
+            ProposalKind::ChangeParam { param_value, .. } => Some(param_value.clone()),
+            _ => None,
+        })
+    }
+    fn field_role_name(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<String>> {
+        Ok(match &self.kind {
+            ProposalKind::BondedRole { role_name, .. } => Some(role_name.clone()),
+            _ => None,
+        })
+    }
+    fn field_bonded_role_type(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<String>> {
+        Ok(match &self.kind {
+            ProposalKind::BondedRole {
+                bonded_role_type, ..
+            } => Some(bonded_role_type.clone()),
+            _ => None,
+        })
+    }
+    fn field_lockup_tx_id(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<String>> {
+        Ok(match &self.kind {
+            ProposalKind::ConfiscateBond { lockup_tx_id } => Some(lockup_tx_id.clone()),
+            _ => None,
+        })
+    }
+    fn field_ticker_symbol(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<String>> {
+        Ok(match &self.kind {
+            ProposalKind::RemoveAsset { ticker_symbol } => Some(ticker_symbol.clone()),
+            _ => None,
+        })
+    }
+}
+
+impl DaoBlindVoteFields for DaoBlindVote {
+    fn field_tx_id(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&String> {
+        Ok(&self.tx_id)
+    }
+    fn field_formatted_stake(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        Ok(self.stake.format(BSQ_PRECISION))
+    }
+    fn field_date(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<UnixMillis> {
+        Ok(self.date.into())
+    }
+}
+
+impl BlockStateHashesFields for BlockStateHashes {
+    fn field_state_type(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<StateHashType> {
+        Ok(self.kind.into())
+    }
+    fn field_height(&self, _executor: &juniper::Executor<'_, GraphQLContext>) -> FieldResult<i32> {
+        Ok(self.height as i32)
+    }
+    fn field_in_conflict(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<bool> {
+        Ok(self.in_conflict())
+    }
+    fn field_variants(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, StateHashVariant, juniper_from_schema::Walked>,
+    ) -> FieldResult<&Vec<StateHashVariant>> {
+        Ok(&self.variants)
+    }
+}
+
+impl StateHashVariantFields for StateHashVariant {
+    fn field_hash(&self, _executor: &juniper::Executor<'_, GraphQLContext>) -> FieldResult<String> {
+        Ok(self.hash.to_hex())
+    }
+    fn field_peers(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&Vec<String>> {
+        Ok(&self.peers)
+    }
+}
+
+fn format_bsq(satoshis: u64) -> String {
+    NumberWithPrecision::new(satoshis, BSQ_PRECISION).format(BSQ_PRECISION)
+}
+
+impl BsqTxFields for BsqTx {
+    fn field_id(&self, _executor: &juniper::Executor<'_, GraphQLContext>) -> FieldResult<&String> {
+        Ok(&self.id)
+    }
+    fn field_block_height(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<i32> {
+        Ok(self.block_height as i32)
+    }
+    fn field_time(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<UnixMillis> {
+        Ok(self.time.into())
+    }
+    fn field_tx_type(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<BsqTransactionType> {
+        Ok(self.tx_type.into())
+    }
+    fn field_inputs(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, BsqTxInput, juniper_from_schema::Walked>,
+    ) -> FieldResult<&Vec<BsqTxInput>> {
+        Ok(&self.inputs)
+    }
+    fn field_outputs(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, BsqTxOutput, juniper_from_schema::Walked>,
+    ) -> FieldResult<&Vec<BsqTxOutput>> {
+        Ok(&self.outputs)
+    }
+    fn field_formatted_bsq_input(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        Ok(format_bsq(self.bsq_input_value))
+    }
+    fn field_formatted_burnt_fee(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        Ok(format_bsq(self.burnt_fee))
+    }
+}
+
+impl BsqTxInputFields for BsqTxInput {
+    fn field_tx_id(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&String> {
+        Ok(&self.tx_id)
+    }
+    fn field_index(&self, _executor: &juniper::Executor<'_, GraphQLContext>) -> FieldResult<i32> {
+        Ok(self.index as i32)
+    }
+}
+
+impl BsqTxOutputFields for BsqTxOutput {
+    fn field_index(&self, _executor: &juniper::Executor<'_, GraphQLContext>) -> FieldResult<i32> {
+        Ok(self.index as i32)
+    }
+    fn field_formatted_value(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        Ok(format_bsq(self.value))
+    }
+    fn field_address(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&Option<String>> {
+        Ok(&self.address)
+    }
+    fn field_output_type(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<BsqOutputType> {
+        Ok(self.output_type.into())
+    }
+    fn field_is_bsq(&self, _executor: &juniper::Executor<'_, GraphQLContext>) -> FieldResult<bool> {
+        Ok(self.output_type.is_bsq())
+    }
+    fn field_spent_info(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, BsqSpentInfo, juniper_from_schema::Walked>,
+    ) -> FieldResult<&Option<BsqSpentInfo>> {
+        Ok(&self.spent)
+    }
+}
+
+impl BsqSpentInfoFields for BsqSpentInfo {
+    fn field_block_height(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<i32> {
+        Ok(self.block_height as i32)
+    }
+    fn field_tx_id(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&String> {
+        Ok(&self.tx_id)
+    }
+    fn field_input_index(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<i32> {
+        Ok(self.input_index as i32)
+    }
+}
+
+impl BsqAddressFields for BsqAddress {
+    fn field_address(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&String> {
+        Ok(&self.address)
+    }
+    fn field_formatted_balance(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        Ok(format_bsq(self.balance))
+    }
+    fn field_unspent_outputs(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+        _trail: &QueryTrail<'_, BsqTxOutput, juniper_from_schema::Walked>,
+    ) -> FieldResult<&Vec<BsqTxOutput>> {
+        Ok(&self.unspent_outputs)
+    }
+    fn field_tx_ids(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&Vec<String>> {
+        Ok(&self.tx_ids)
+    }
+}
+
+impl BsqSupplyFields for BsqSupply {
+    fn field_chain_height(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<i32>> {
+        Ok(self.chain_height.map(|height| height as i32))
+    }
+    fn field_formatted_genesis(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        Ok(format_bsq(self.genesis))
+    }
+    fn field_formatted_burnt(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        Ok(format_bsq(self.burnt))
+    }
+    fn field_formatted_unspent(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        Ok(format_bsq(self.unspent))
+    }
+    fn field_formatted_locked_in_bonds(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<String> {
+        Ok(format_bsq(self.locked_in_bonds))
+    }
+    fn field_unspent_outputs(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<i32> {
+        Ok(self.unspent_outputs as i32)
+    }
+}
+
+impl DisputeAgentFields for DisputeAgent {
+    fn field_role(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<AgentRole> {
+        Ok(self.role.into())
+    }
+    fn field_node_address(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<Option<String>> {
+        Ok(self.node_address.as_ref().map(|addr| addr.to_string()))
+    }
+    fn field_language_codes(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&Vec<String>> {
+        Ok(&self.language_codes)
+    }
+    fn field_registration_date(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<UnixMillis> {
+        Ok(self.registration_date.into())
+    }
+    fn field_email_address(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&Option<String>> {
+        Ok(&self.email_address)
+    }
+    fn field_info(
+        &self,
+        _executor: &juniper::Executor<'_, GraphQLContext>,
+    ) -> FieldResult<&Option<String>> {
+        Ok(&self.info)
+    }
 }
 
 impl TickerFields for Ticker {
@@ -796,6 +1594,118 @@ mod convert {
             }
         }
     }
+    impl From<BsqTxType> for BsqTransactionType {
+        fn from(tx_type: BsqTxType) -> BsqTransactionType {
+            match tx_type {
+                BsqTxType::Genesis => BsqTransactionType::Genesis,
+                BsqTxType::TransferBsq => BsqTransactionType::TransferBsq,
+                BsqTxType::PayTradeFee => BsqTransactionType::PayTradeFee,
+                BsqTxType::Proposal => BsqTransactionType::Proposal,
+                BsqTxType::CompensationRequest => BsqTransactionType::CompensationRequest,
+                BsqTxType::ReimbursementRequest => BsqTransactionType::ReimbursementRequest,
+                BsqTxType::BlindVote => BsqTransactionType::BlindVote,
+                BsqTxType::VoteReveal => BsqTransactionType::VoteReveal,
+                BsqTxType::Lockup => BsqTransactionType::Lockup,
+                BsqTxType::Unlock => BsqTransactionType::Unlock,
+                BsqTxType::AssetListingFee => BsqTransactionType::AssetListingFee,
+                BsqTxType::ProofOfBurn => BsqTransactionType::ProofOfBurn,
+            }
+        }
+    }
+    impl From<BsqTxOutputType> for BsqOutputType {
+        fn from(output_type: BsqTxOutputType) -> BsqOutputType {
+            match output_type {
+                BsqTxOutputType::GenesisOutput => BsqOutputType::GenesisOutput,
+                BsqTxOutputType::BsqOutput => BsqOutputType::BsqOutput,
+                BsqTxOutputType::BtcOutput => BsqOutputType::BtcOutput,
+                BsqTxOutputType::ProposalOpReturnOutput => BsqOutputType::ProposalOpReturnOutput,
+                BsqTxOutputType::CompReqOpReturnOutput => BsqOutputType::CompReqOpReturnOutput,
+                BsqTxOutputType::ReimbursementOpReturnOutput => {
+                    BsqOutputType::ReimbursementOpReturnOutput
+                }
+                BsqTxOutputType::IssuanceCandidateOutput => BsqOutputType::IssuanceCandidateOutput,
+                BsqTxOutputType::BlindVoteLockStakeOutput => {
+                    BsqOutputType::BlindVoteLockStakeOutput
+                }
+                BsqTxOutputType::BlindVoteOpReturnOutput => BsqOutputType::BlindVoteOpReturnOutput,
+                BsqTxOutputType::VoteRevealUnlockStakeOutput => {
+                    BsqOutputType::VoteRevealUnlockStakeOutput
+                }
+                BsqTxOutputType::VoteRevealOpReturnOutput => {
+                    BsqOutputType::VoteRevealOpReturnOutput
+                }
+                BsqTxOutputType::AssetListingFeeOpReturnOutput => {
+                    BsqOutputType::AssetListingFeeOpReturnOutput
+                }
+                BsqTxOutputType::ProofOfBurnOpReturnOutput => {
+                    BsqOutputType::ProofOfBurnOpReturnOutput
+                }
+                BsqTxOutputType::LockupOutput => BsqOutputType::LockupOutput,
+                BsqTxOutputType::LockupOpReturnOutput => BsqOutputType::LockupOpReturnOutput,
+                BsqTxOutputType::UnlockOutput => BsqOutputType::UnlockOutput,
+            }
+        }
+    }
+    impl From<StateHashKind> for StateHashType {
+        fn from(kind: StateHashKind) -> StateHashType {
+            match kind {
+                StateHashKind::Dao => StateHashType::Dao,
+                StateHashKind::Proposal => StateHashType::Proposal,
+                StateHashKind::BlindVote => StateHashType::BlindVote,
+            }
+        }
+    }
+    impl From<StateHashType> for StateHashKind {
+        fn from(state_type: StateHashType) -> StateHashKind {
+            match state_type {
+                StateHashType::Dao => StateHashKind::Dao,
+                StateHashType::Proposal => StateHashKind::Proposal,
+                StateHashType::BlindVote => StateHashKind::BlindVote,
+            }
+        }
+    }
+    impl From<DisputeAgentRole> for AgentRole {
+        fn from(role: DisputeAgentRole) -> AgentRole {
+            match role {
+                DisputeAgentRole::Arbitrator => AgentRole::Arbitrator,
+                DisputeAgentRole::Mediator => AgentRole::Mediator,
+                DisputeAgentRole::RefundAgent => AgentRole::RefundAgent,
+            }
+        }
+    }
+    impl From<AgentRole> for DisputeAgentRole {
+        fn from(role: AgentRole) -> DisputeAgentRole {
+            match role {
+                AgentRole::Arbitrator => DisputeAgentRole::Arbitrator,
+                AgentRole::Mediator => DisputeAgentRole::Mediator,
+                AgentRole::RefundAgent => DisputeAgentRole::RefundAgent,
+            }
+        }
+    }
+    impl From<AccountSignState> for SignState {
+        fn from(state: AccountSignState) -> SignState {
+            match state {
+                AccountSignState::Arbitrator => SignState::Arbitrator,
+                AccountSignState::PeerInitial => SignState::PeerInitial,
+                AccountSignState::PeerLimitLifted => SignState::PeerLimitLifted,
+                AccountSignState::PeerSigner => SignState::PeerSigner,
+                AccountSignState::Unsigned => SignState::Unsigned,
+            }
+        }
+    }
+    impl From<&ProposalKind> for ProposalType {
+        fn from(kind: &ProposalKind) -> ProposalType {
+            match kind {
+                ProposalKind::Compensation { .. } => ProposalType::Compensation,
+                ProposalKind::Reimbursement { .. } => ProposalType::Reimbursement,
+                ProposalKind::ChangeParam { .. } => ProposalType::ChangeParam,
+                ProposalKind::BondedRole { .. } => ProposalType::BondedRole,
+                ProposalKind::ConfiscateBond { .. } => ProposalType::ConfiscateBond,
+                ProposalKind::Generic => ProposalType::Generic,
+                ProposalKind::RemoveAsset { .. } => ProposalType::RemoveAsset,
+            }
+        }
+    }
     impl From<SystemTime> for UnixMillis {
         fn from(time: SystemTime) -> Self {
             UnixMillis(
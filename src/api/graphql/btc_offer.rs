@@ -59,6 +59,8 @@ mod tests {
             },
             "PAYMENT_METHOD".into(),
             "OFFER_FEE_TX_ID".into(),
+            None,
+            None,
             UNIX_EPOCH,
             0.into(),
         )
@@ -82,6 +84,8 @@ mod tests {
             },
             "PAYMENT_METHOD".into(),
             "OFFER_FEE_TX_ID".into(),
+            None,
+            None,
             UNIX_EPOCH,
             0.into(),
         )
@@ -1,7 +1,16 @@
 use super::graphql::*;
 use crate::{
     bisq::NodeAddress,
-    domain::{offer::OfferBook, statistics::*},
+    domain::{
+        account_age::AccountAgeCache,
+        alert::AlertCache,
+        dao::{BsqBlocks, DaoStateHashes, GovernanceStore},
+        dispute_agent::DisputeAgentRegistry,
+        filter::FilterCache,
+        offer::OfferBook,
+        signed_witness::SignedWitnessCache,
+        statistics::*,
+    },
     p2p::Status,
     prelude::*,
 };
@@ -12,6 +21,14 @@ use std::{collections::HashMap, io, time::UNIX_EPOCH};
 pub fn listen(
     port: u16,
     offer_book: Addr<OfferBook>,
+    filter_cache: FilterCache,
+    alert_cache: AlertCache,
+    account_ages: AccountAgeCache,
+    signed_witnesses: SignedWitnessCache,
+    governance: GovernanceStore,
+    dao_state_hashes: DaoStateHashes,
+    bsq_blocks: BsqBlocks,
+    dispute_agents: Addr<DisputeAgentRegistry>,
     p2p_status: Status,
     stats_cache: Option<StatsCache>,
 ) -> Result<(), io::Error> {
@@ -19,13 +36,23 @@ pub fn listen(
         #[cfg(feature = "statistics")]
         stats_cache: stats_cache.unwrap(),
         offer_book,
+        filter_cache,
+        alert_cache: alert_cache.clone(),
+        account_ages,
+        signed_witnesses,
+        governance,
+        dao_state_hashes,
+        bsq_blocks: bsq_blocks.clone(),
+        dispute_agents,
     };
-    listen_with_context(port, p2p_status, gql_context)
+    listen_with_context(port, p2p_status, alert_cache, bsq_blocks, gql_context)
 }
 
 fn listen_with_context(
     port: u16,
     p2p_status: Status,
+    alert_cache: AlertCache,
+    bsq_blocks: BsqBlocks,
     gql_context: GraphQLContextWrapper,
 ) -> Result<(), io::Error> {
     let schema = std::sync::Arc::new(create_schema());
@@ -35,7 +62,12 @@ fn listen_with_context(
             .wrap(Logger::default())
             .route("/ping", web::get().to(|| "pong"))
             .data(p2p_status.clone())
-            .service(web::resource("/status").route(web::get().to(status)))
+            .service(
+                web::resource("/status")
+                    .data(alert_cache.clone())
+                    .data(bsq_blocks.clone())
+                    .route(web::get().to(status)),
+            )
             .service(
                 web::resource("/graphql")
                     .data(schema.clone())
@@ -59,12 +91,46 @@ struct ConnInfo {
     alive_at: u64,
 }
 #[derive(serde::Serialize)]
+struct TorInfo {
+    bootstrap_progress: u8,
+    circuits_built: u32,
+    circuits_failed: u32,
+    last_circuit_failure: Option<String>,
+    descriptor_published: bool,
+    descriptor_uploads: u32,
+    descriptor_upload_failures: u32,
+}
+#[derive(serde::Serialize)]
+struct AlertInfo {
+    message: String,
+    version: String,
+    is_update_info: bool,
+    // Millis like the UnixMillis of the graphql api
+    created_at: u64,
+}
+#[derive(serde::Serialize)]
+struct PrivateNotificationInfo {
+    message: String,
+    sender: Option<String>,
+    received_at: u64,
+}
+#[derive(serde::Serialize)]
 struct StatusResponse {
     state: String,
+    seed: Option<String>,
+    failed_seed_attempts: u32,
+    tor: Option<TorInfo>,
+    alert: Option<AlertInfo>,
+    private_notification: Option<PrivateNotificationInfo>,
+    bsq_chain_height: Option<u32>,
     connections: HashMap<String, ConnInfo>,
 }
 
-fn status(status: web::Data<Status>) -> HttpResponse {
+fn status(
+    status: web::Data<Status>,
+    alert_cache: web::Data<AlertCache>,
+    bsq_blocks: web::Data<BsqBlocks>,
+) -> HttpResponse {
     let connections: HashMap<String, ConnInfo> = status
         .connections()
         .iter()
@@ -82,8 +148,42 @@ fn status(status: web::Data<Status>) -> HttpResponse {
             )
         })
         .collect();
+    let bootstrap_state = status.bootstrap_state();
     HttpResponse::Ok().json(StatusResponse {
-        state: status.bootstrap_state().to_string(),
+        state: bootstrap_state.to_string(),
+        seed: bootstrap_state.seed().map(NodeAddress::to_string),
+        failed_seed_attempts: bootstrap_state.failed_attempts(),
+        tor: status.tor_health().map(|health| TorInfo {
+            bootstrap_progress: health.bootstrap_progress,
+            circuits_built: health.circuits_built,
+            circuits_failed: health.circuits_failed,
+            last_circuit_failure: health.last_circuit_failure,
+            descriptor_published: health.descriptor_published,
+            descriptor_uploads: health.descriptor_uploads,
+            descriptor_upload_failures: health.descriptor_upload_failures,
+        }),
+        alert: alert_cache.alert().map(|alert| AlertInfo {
+            message: alert.message.clone(),
+            version: alert.version.clone(),
+            is_update_info: alert.is_update_info,
+            created_at: alert
+                .created_at
+                .duration_since(UNIX_EPOCH)
+                .expect("Time reversed")
+                .as_millis() as u64,
+        }),
+        private_notification: alert_cache.private_notification().map(|notification| {
+            PrivateNotificationInfo {
+                message: notification.message.clone(),
+                sender: notification.sender.as_ref().map(NodeAddress::to_string),
+                received_at: notification
+                    .received_at
+                    .duration_since(UNIX_EPOCH)
+                    .expect("Time reversed")
+                    .as_millis() as u64,
+            }
+        }),
+        bsq_chain_height: bsq_blocks.chain_height(),
         connections,
     })
 }
@@ -21,22 +21,111 @@ impl FromStr for BaseCurrencyNetwork {
     }
 }
 
+impl BaseCurrencyNetwork {
+    // Same naming as the sub directories of the bisq data dir
+    pub fn data_dir_name(self) -> &'static str {
+        match self {
+            BaseCurrencyNetwork::BtcMainnet => "btc_mainnet",
+            BaseCurrencyNetwork::BtcTestnet => "btc_testnet",
+            BaseCurrencyNetwork::BtcRegtest => "btc_regtest",
+        }
+    }
+}
+
 pub(super) const P2P_NETWORK_VERSION: i32 = 1;
+// Used instead of the production keys when bisq runs with --useDevPrivilegeKeys
+pub(super) const DEV_PRIVILEGE_PUB_KEY: &str =
+    "027a381b5333a56e1cc3d90d3a7d07f26509adf7029ed06fc997c656621f8da1ee";
+pub const MAX_PERMITTED_MESSAGE_SIZE: usize = 10 * 1024 * 1024;
 
 pub fn seed_nodes(network: BaseCurrencyNetwork) -> Vec<NodeAddress> {
+    let (port, host_names): (i32, &[&str]) = match network {
+        BaseCurrencyNetwork::BtcRegtest => (4002, &["127.0.0.1"]),
+        BaseCurrencyNetwork::BtcTestnet => (8001, &["m5izk3fvjsjbmkqi.onion"]),
+        BaseCurrencyNetwork::BtcMainnet => (
+            8000,
+            &[
+                "wizseedscybbttk4bmb2lzvbuk2jtect37lcpva4l3twktmkzemwbead.onion",
+                "wizseed7ab2gi3x267xahrp2pkndyrovczezzb46jk6quvguciuyqrid.onion",
+                "devinv3rhon24gqf5v6ondoqgyrbzyqihzyouzv7ptltsewhfmox2zqd.onion",
+                "sn3emzy56u3mxzsr4geysc52feoq5qt7ja56km6gygwnszkshunn2sid.onion",
+                "sn4emzywye3dhjouv7jig677qepg7fnusjidw74fbwneieruhmi7fuyd.onion",
+                "sn5emzyvxuildv34n6jewfp2zeota4aq63fsl5yyilnvksezr3htveqd.onion",
+                "sn2bisqad7ncazupgbd3dcedqh5ptirgwofw63djwpdtftwhddo75oid.onion",
+            ],
+        ),
+    };
+    host_names
+        .iter()
+        .map(|host_name| NodeAddress {
+            host_name: host_name.to_string(),
+            port,
+        })
+        .collect()
+}
+
+// Keys allowed to publish a Filter, taken from bisq's FilterManager
+pub fn filter_pub_keys(network: BaseCurrencyNetwork) -> &'static [&'static str] {
     match network {
-        BaseCurrencyNetwork::BtcRegtest => vec![NodeAddress {
-            host_name: "127.0.0.1".to_string(),
-            port: 4002,
-        }],
-        BaseCurrencyNetwork::BtcTestnet => vec![NodeAddress {
-            host_name: "m5izk3fvjsjbmkqi.onion".to_string(),
-            port: 8001,
-        }],
-        BaseCurrencyNetwork::BtcMainnet => vec![NodeAddress {
-            host_name: "jhgcy2won7xnslrb.onion".to_string(),
-            port: 8000,
-        }],
+        BaseCurrencyNetwork::BtcMainnet => {
+            &["022ac7b7766b0aedff82962522c2c14fb8d1961dabef6e5cfd10edc679456a32f1"]
+        }
+        _ => &[DEV_PRIVILEGE_PUB_KEY],
+    }
+}
+
+// Keys allowed to publish an Alert, taken from bisq's AlertManager
+pub fn alert_pub_keys(network: BaseCurrencyNetwork) -> &'static [&'static str] {
+    match network {
+        BaseCurrencyNetwork::BtcMainnet => {
+            &["036d8a1dfcb406886037d2381da006358722823e1940acc2598c844bbc0fd1026f"]
+        }
+        _ => &[DEV_PRIVILEGE_PUB_KEY],
+    }
+}
+
+// Keys allowed to send a PrivateNotificationMessage, taken from bisq's PrivateNotificationManager
+pub fn private_notification_pub_keys(network: BaseCurrencyNetwork) -> &'static [&'static str] {
+    match network {
+        BaseCurrencyNetwork::BtcMainnet => {
+            &["02ba7c5de295adfe57b60029f3637a2c6b1d0e969a8aaefb9e0ddc3a7963f26925"]
+        }
+        _ => &[DEV_PRIVILEGE_PUB_KEY],
+    }
+}
+
+// Btc keys of the arbitrators that are the roots of every account signing chain,
+// taken from bisq's ArbitratorManager
+pub fn arbitrator_pub_keys(network: BaseCurrencyNetwork) -> &'static [&'static str] {
+    match network {
+        BaseCurrencyNetwork::BtcMainnet => &[
+            "0365c6af94681dbee69de1851f98d4684063bf5c2d64b1c73ed5d90434f375a054",
+            "031c502a60f9dbdb5ae5e438a79819e4e1f417211dd537ac12c9bc23246534c4bd",
+            "02c1e5a242387b6d5319ce27246cea6edaaf51c3550591b528d2578a4753c56c2c",
+            "025c319faf7067d9299590dd6c97fe7e56cd4dac61205ccee1cd1fc390142390a2",
+            "038f6e24c2bfe5d51d0a290f20a9a657c270b94ef2b9c12cd15ca3725fa798fc55",
+            "0255256ff7fb615278c4544a9bbd3f5298b903b8a011cd7889be19b6b1c45cbefe",
+            "024a3a37289f08c910fbd925ebc72b946f33feaeff451a4738ee82037b4cda2e95",
+            "02a88b75e9f0f8afba1467ab26799dcc38fd7a6468fb2795444b425eb43e2c10bd",
+            "02349a51512c1c04c67118386f4d27d768c5195a83247c150a4b722d161722ba81",
+            "03f718a2e0dc672c7cdec0113e72c3322efc70412bb95870750d25c32cd98de17d",
+            "028ff47ee2c56e66313928975c58fa4f1b19a0f81f3a96c4e9c9c3c6768075509e",
+            "02b517c0cbc3a49548f448ddf004ed695c5a1c52ec110be1bfd65fa0ca0761c94b",
+            "03df837a3a0f3d858e82f3356b71d1285327f101f7c10b404abed2abc1c94e7169",
+            "0203a90fb2ab698e524a5286f317a183a84327b8f8c3f7fa4a98fec9e1cefd6b72",
+            "023c99cc073b851c892d8c43329ca3beb5d2213ee87111af49884e3ce66cbd5ba5",
+        ],
+        _ => &[DEV_PRIVILEGE_PUB_KEY],
+    }
+}
+
+// Length of a DAO cycle in blocks, the sum of the phase durations in bisq's Param
+// defaults. Phase changes the DAO voted on are not taken into account.
+pub fn dao_cycle_blocks(network: BaseCurrencyNetwork) -> u32 {
+    match network {
+        BaseCurrencyNetwork::BtcMainnet => 3601 + 149 + 451 + 9 + 451 + 9 + 10,
+        BaseCurrencyNetwork::BtcTestnet => 380 + 10 + 300 + 10 + 300 + 10 + 2,
+        BaseCurrencyNetwork::BtcRegtest => 4 + 1 + 2 + 1 + 2 + 1 + 2,
     }
 }
 
@@ -62,6 +151,11 @@ lazy_static! {
             .for_each(|c| vec.push(*c as i32));
         vec
     };
+    pub static ref SEED_NODE_CAPABILITIES: Vec<i32> = {
+        let mut vec = LOCAL_CAPABILITIES.clone();
+        vec.push(Capability::SeedNode as i32);
+        vec
+    };
 }
 static SUPPORTED_CAPABILITIES: [Capability; 12] = [
     Capability::TradeStatistics,
@@ -78,7 +172,7 @@ static SUPPORTED_CAPABILITIES: [Capability; 12] = [
     Capability::TradeStatisticsHashUpdate,
 ];
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Capability {
     TradeStatistics, // Not required anymore as no old clients out there not having that support
     TradeStatistics2, // Not required anymore as no old clients out there not having that support
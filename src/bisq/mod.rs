@@ -4,6 +4,7 @@ pub mod constants;
 #[macro_use]
 pub mod payload;
 pub mod correlation;
+pub mod persistence;
 
 pub use hash::*;
 pub use payload::NodeAddress;
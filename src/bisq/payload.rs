@@ -6,13 +6,20 @@ include!("../generated/payload_macros.rs");
 pub mod kind;
 
 use super::{constants::*, hash::*};
-use crate::prelude::{ripemd160, sha256, Hash};
+use crate::prelude::{ripemd160, sha256, FromHex, Hash, ToHex};
+use bitcoin::util::misc::signed_msg_hash;
+use lazy_static::lazy_static;
 use openssl::{dsa::Dsa, pkey::*, sign::Verifier};
 use rand::{thread_rng, Rng};
+use secp256k1::{
+    recovery::{RecoverableSignature, RecoveryId},
+    PublicKey, Secp256k1, VerifyOnly,
+};
 use std::{
     fmt, io,
     net::{SocketAddr, ToSocketAddrs},
     str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
     vec,
 };
 
@@ -20,23 +27,76 @@ pub fn gen_nonce() -> i32 {
     thread_rng().gen()
 }
 
+lazy_static! {
+    static ref SECP: Secp256k1<VerifyOnly> = Secp256k1::verification_only();
+}
+
+// Checks a signature created by bitcoinj's ECKey.signMessage (base64 encoded
+// compact signature over the bitcoin signed message hash)
+pub fn verify_signed_message(pub_key: &[u8], message: &str, signature_base64: &str) -> bool {
+    let verify = || {
+        let signature = base64::decode(signature_base64).ok()?;
+        if signature.len() != 65 || signature[0] < 27 || signature[0] > 34 {
+            return None;
+        }
+        let recovery_id = RecoveryId::from_i32(i32::from((signature[0] - 27) & 3)).ok()?;
+        let signature = RecoverableSignature::from_compact(&signature[1..], recovery_id).ok()?;
+        let hash = secp256k1::Message::from_slice(&signed_msg_hash(message).into_inner()).ok()?;
+        let recovered = SECP.recover(&hash, &signature).ok()?;
+        Some(PublicKey::from_slice(pub_key).ok()? == recovered)
+    };
+    verify().unwrap_or(false)
+}
+fn verify_signed_by_any(pub_keys: &[&str], message: &str, signature_base64: &str) -> bool {
+    pub_keys
+        .iter()
+        .filter_map(|key| Vec::<u8>::from_hex(key).ok())
+        .any(|key| verify_signed_message(&key, message, signature_base64))
+}
+
 impl ToSocketAddrs for NodeAddress {
     type Iter = vec::IntoIter<SocketAddr>;
     fn to_socket_addrs(&self) -> io::Result<Self::Iter> {
         (&*self.host_name, self.port as u16).to_socket_addrs()
     }
 }
+impl NodeAddress {
+    pub fn is_valid(&self) -> bool {
+        self.port > 0 && self.port <= i32::from(u16::max_value()) && is_valid_host(&self.host_name)
+    }
+}
+// v2 onions are 16 and v3 onions 56 base32 characters, v3 ones always end with the version 'd'
+fn is_valid_host(host_name: &str) -> bool {
+    if !host_name.ends_with(".onion") {
+        return !host_name.is_empty();
+    }
+    let service_id = &host_name[..host_name.len() - ".onion".len()];
+    let is_base32 = service_id
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || ('2'..='7').contains(&c));
+    is_base32
+        && match service_id.len() {
+            16 => true,
+            56 => service_id.ends_with('d'),
+            _ => false,
+        }
+}
 impl FromStr for NodeAddress {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut iter = s.split(':');
-        match (iter.next(), iter.next()) {
-            (Some(host_name), Some(port)) if u16::from_str(&port).is_ok() => Ok(Self {
+        let addr = match (iter.next(), iter.next()) {
+            (Some(host_name), Some(port)) if u16::from_str(&port).is_ok() => Self {
                 host_name: host_name.to_string(),
                 port: u16::from_str(&port).unwrap() as i32,
-            }),
-            (_, Some(_)) => Err("Couldn't parse port".to_string()),
-            _ => Err("Couldn't parse node address".to_string()),
+            },
+            (_, Some(_)) => return Err("Couldn't parse port".to_string()),
+            _ => return Err("Couldn't parse node address".to_string()),
+        };
+        if addr.is_valid() {
+            Ok(addr)
+        } else {
+            Err(format!("'{}' is not a valid node address", s))
         }
     }
 }
@@ -91,8 +151,49 @@ impl StoragePayload {
         }
         .into()
     }
+
+    // TTLs are taken from the corresponding payload classes in bisq
+    pub fn ttl(&self) -> Duration {
+        const DAY: u64 = 24 * 60 * 60;
+        let secs = match self.message.as_ref() {
+            Some(storage_payload::Message::OfferPayload(_)) => 9 * 60,
+            Some(storage_payload::Message::Alert(_)) => 90 * DAY,
+            Some(storage_payload::Message::Arbitrator(_))
+            | Some(storage_payload::Message::Mediator(_))
+            | Some(storage_payload::Message::RefundAgent(_)) => 10 * DAY,
+            Some(storage_payload::Message::Filter(_)) => 180 * DAY,
+            Some(storage_payload::Message::TradeStatistics(_)) => 30 * DAY,
+            Some(storage_payload::Message::MailboxStoragePayload(_)) => 15 * DAY,
+            Some(storage_payload::Message::TempProposalPayload(_)) => 60 * DAY,
+            None => 0,
+        };
+        Duration::from_secs(secs)
+    }
 }
 impl ProtectedStorageEntry {
+    pub fn is_expired(&self) -> bool {
+        if self.creation_time_stamp < 0 {
+            return true;
+        }
+        self.storage_payload
+            .as_ref()
+            .map(|payload| {
+                UNIX_EPOCH + Duration::from_millis(self.creation_time_stamp as u64) + payload.ttl()
+                    < SystemTime::now()
+            })
+            .unwrap_or(true)
+    }
+    // Like bisq's maybeAdjustCreationTimeStamp, so peers can't keep an entry from
+    // expiring by dating it into the future
+    pub fn adjust_creation_time_stamp(&mut self) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as i64;
+        if self.creation_time_stamp > now {
+            self.creation_time_stamp = now;
+        }
+    }
     fn owner_pub_key(&self) -> Option<PKey<Public>> {
         PKey::from_dsa(Dsa::public_key_from_der(&self.owner_pub_key_bytes).ok()?).ok()
     }
@@ -125,6 +226,76 @@ impl ProtectedStorageEntry {
             })
     }
 }
+impl Filter {
+    // The dev signature only covers the banned entries, see getHexFromData in bisq's FilterManager
+    pub fn verify_signature(&self, pub_keys: &[&str]) -> bool {
+        use prost::Message as _;
+
+        let signed_data = Filter {
+            banned_offer_ids: self.banned_offer_ids.clone(),
+            banned_node_address: self.banned_node_address.clone(),
+            banned_payment_accounts: self.banned_payment_accounts.clone(),
+            banned_currencies: self.banned_currencies.clone(),
+            banned_payment_methods: self.banned_payment_methods.clone(),
+            ..Default::default()
+        };
+        let mut serialized = Vec::with_capacity(signed_data.encoded_len());
+        signed_data
+            .encode(&mut serialized)
+            .expect("Could not encode filter");
+        verify_signed_by_any(pub_keys, &serialized.to_hex(), &self.signature_as_base64)
+    }
+}
+impl Alert {
+    // Bisq signs the hex encoded utf8 bytes of the message
+    pub fn verify_signature(&self, pub_keys: &[&str]) -> bool {
+        verify_signed_by_any(
+            pub_keys,
+            &self.message.as_bytes().to_hex(),
+            &self.signature_as_base64,
+        )
+    }
+}
+impl PrivateNotificationPayload {
+    pub fn verify_signature(&self, pub_keys: &[&str]) -> bool {
+        verify_signed_by_any(
+            pub_keys,
+            &self.message.as_bytes().to_hex(),
+            &self.signature_as_base64,
+        )
+    }
+}
+impl SignedWitness {
+    pub fn is_signed_by_arbitrator(&self) -> bool {
+        self.verification_method == signed_witness::VerificationMethod::Arbitrator as i32
+    }
+    // Mirrors verifySignature in bisq's SignedWitnessService. Arbitrators sign with their
+    // btc key like bitcoinj's signMessage, peers sign the raw witness hash with their DSA key.
+    // Whether the signer is allowed to sign at all is not checked here.
+    pub fn verify_signature(&self) -> bool {
+        if self.is_signed_by_arbitrator() {
+            match std::str::from_utf8(&self.signature) {
+                Ok(signature_base64) => verify_signed_message(
+                    &self.signer_pub_key,
+                    &self.account_age_witness_hash.to_hex(),
+                    signature_base64,
+                ),
+                Err(_) => false,
+            }
+        } else {
+            let verify = || {
+                let pub_key =
+                    PKey::from_dsa(Dsa::public_key_from_der(&self.signer_pub_key).ok()?).ok()?;
+                let hash = sha256::Hash::hash(&self.account_age_witness_hash);
+                let verifier = Verifier::new_without_digest(&pub_key).ok()?;
+                verifier
+                    .verify_oneshot(&self.signature, &hash.into_inner())
+                    .ok()
+            };
+            verify().unwrap_or(false)
+        }
+    }
+}
 impl RefreshOfferMessage {
     pub fn payload_hash(&self) -> SequencedMessageHash {
         SequencedMessageHash::new(
@@ -233,7 +404,7 @@ macro_rules! extractor {
 for_all_payloads!(extractor);
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use prost::Message;
 
@@ -252,7 +423,103 @@ mod tests {
         assert!(StoragePayload::decode(serialized) == Ok(bisq));
     }
 
-    const BISQ_HEX: &[u8] = &[
+    #[test]
+    fn clamp_creation_time_stamp() {
+        let mut entry = ProtectedStorageEntry {
+            storage_payload: Some(StoragePayload {
+                message: Some(storage_payload::Message::Filter(Filter::default())),
+            }),
+            creation_time_stamp: i64::max_value(),
+            ..Default::default()
+        };
+        entry.adjust_creation_time_stamp();
+        assert!(entry.creation_time_stamp < i64::max_value());
+        assert!(!entry.is_expired());
+        entry.creation_time_stamp = -1;
+        assert!(entry.is_expired());
+    }
+
+    #[test]
+    fn verify_filter_signature() {
+        use secp256k1::SecretKey;
+
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let pub_key = PublicKey::from_secret_key(&secp, &secret_key)
+            .serialize()
+            .to_hex();
+        let mut filter = Filter {
+            banned_offer_ids: vec!["offer-id".into()],
+            banned_node_address: vec!["5quyxpxheyvzmb2d.onion:9999".into()],
+            ..Default::default()
+        };
+        let mut signed_data = Vec::new();
+        filter.encode(&mut signed_data).unwrap();
+        let hash = signed_msg_hash(&signed_data.to_hex());
+        let (recovery_id, signature) = secp
+            .sign_recoverable(
+                &secp256k1::Message::from_slice(&hash.into_inner()).unwrap(),
+                &secret_key,
+            )
+            .serialize_compact();
+        // Header of a compressed key signature as written by bitcoinj
+        let mut compact = vec![31 + recovery_id.to_i32() as u8];
+        compact.extend_from_slice(&signature);
+        filter.signature_as_base64 = base64::encode(&compact);
+        filter.owner_pub_key_bytes = vec![1, 2, 3];
+
+        assert!(filter.verify_signature(&[&pub_key]));
+        assert!(!filter.verify_signature(&[DEV_PRIVILEGE_PUB_KEY]));
+        filter.banned_offer_ids.push("another-offer".into());
+        assert!(!filter.verify_signature(&[&pub_key]));
+    }
+
+    #[test]
+    fn verify_peer_signed_witness() {
+        use openssl::sign::Signer;
+
+        let key = PKey::from_dsa(Dsa::generate(1024).unwrap()).unwrap();
+        let account_age_witness_hash = vec![7; 20];
+        let hash = sha256::Hash::hash(&account_age_witness_hash);
+        let mut witness = SignedWitness {
+            verification_method: signed_witness::VerificationMethod::Trade as i32,
+            signature: Signer::new_without_digest(&key)
+                .unwrap()
+                .sign_oneshot_to_vec(&hash.into_inner())
+                .unwrap(),
+            signer_pub_key: key.public_key_to_der().unwrap(),
+            account_age_witness_hash,
+            ..Default::default()
+        };
+        assert!(witness.verify_signature());
+        witness.account_age_witness_hash[0] = 8;
+        assert!(!witness.verify_signature());
+    }
+
+    #[test]
+    fn parse_node_address() {
+        assert!(NodeAddress::from_str("127.0.0.1:4002").is_ok());
+        assert!(NodeAddress::from_str("5quyxpxheyvzmb2d.onion:8000").is_ok());
+        assert!(NodeAddress::from_str(
+            "wizseedscybbttk4bmb2lzvbuk2jtect37lcpva4l3twktmkzemwbead.onion:8000"
+        )
+        .is_ok());
+        assert!(NodeAddress::from_str("5quyxpxheyvzmb2.onion:8000").is_err());
+        assert!(NodeAddress::from_str(
+            "wizseedscybbttk4bmb2lzvbuk2jtect37lcpva4l3twktmkzemwbea1.onion:8000"
+        )
+        .is_err());
+        assert!(NodeAddress::from_str("5quyxpxheyvzmb2d.onion:0").is_err());
+        for network in &[
+            BaseCurrencyNetwork::BtcMainnet,
+            BaseCurrencyNetwork::BtcTestnet,
+            BaseCurrencyNetwork::BtcRegtest,
+        ] {
+            assert!(seed_nodes(*network).iter().all(NodeAddress::is_valid));
+        }
+    }
+
+    pub(crate) const BISQ_HEX: &[u8] = &[
         0x3A, 0x9D, 0x0A, 0x0A, 0x2F, 0x41, 0x4B, 0x52, 0x55, 0x56, 0x43, 0x2D, 0x38, 0x63, 0x38,
         0x30, 0x35, 0x61, 0x34, 0x39, 0x2D, 0x63, 0x31, 0x61, 0x33, 0x2D, 0x34, 0x35, 0x62, 0x34,
         0x2D, 0x39, 0x61, 0x30, 0x64, 0x2D, 0x30, 0x64, 0x36, 0x62, 0x63, 0x32, 0x65, 0x34, 0x63,
@@ -1,9 +1,20 @@
 use bytes::*;
 use prost::{encoding, DecodeError, Message};
 
-#[cfg_attr(feature = "dummy-seed", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "dummy-seed", feature = "inspect"),
+    derive(serde::Serialize, serde::Deserialize)
+)]
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct JavaStringMapEntry(String, String);
+impl JavaStringMapEntry {
+    pub fn key(&self) -> &str {
+        &self.0
+    }
+    pub fn value(&self) -> &str {
+        &self.1
+    }
+}
 
 // Java protobuf lib always serializes key and value in map fields
 // Prost skips serializing value if it == the default ("" for string)
@@ -1,4 +1,4 @@
-use crate::bisq::payload::*;
+use crate::bisq::{constants::Capability, payload::*};
 
 #[allow(dead_code)]
 pub enum StoragePayloadKind {
@@ -12,8 +12,19 @@ pub enum StoragePayloadKind {
     MailboxStoragePayload,
     OfferPayload,
     TempProposalPayload,
+    RefundAgent,
     Unknown,
 }
+impl StoragePayloadKind {
+    pub fn required_capability(&self) -> Option<Capability> {
+        match self {
+            Self::Mediator => Some(Capability::Mediation),
+            Self::TempProposalPayload => Some(Capability::Proposal),
+            Self::RefundAgent => Some(Capability::RefundAgent),
+            _ => None,
+        }
+    }
+}
 impl Default for StoragePayloadKind {
     fn default() -> Self {
         Self::Unknown
@@ -26,8 +37,19 @@ impl From<&ProtectedStorageEntry> for StoragePayloadKind {
             .as_ref()
             .and_then(|p| p.message.as_ref())
             .map(|m| match m {
+                storage_payload::Message::Alert(_) => StoragePayloadKind::Alert,
+                storage_payload::Message::Arbitrator(_) => StoragePayloadKind::Arbitrator,
+                storage_payload::Message::Mediator(_) => StoragePayloadKind::Mediator,
+                storage_payload::Message::Filter(_) => StoragePayloadKind::Filter,
+                storage_payload::Message::TradeStatistics(_) => StoragePayloadKind::TradeStatistics,
+                storage_payload::Message::MailboxStoragePayload(_) => {
+                    StoragePayloadKind::MailboxStoragePayload
+                }
                 storage_payload::Message::OfferPayload(_) => StoragePayloadKind::OfferPayload,
-                _ => StoragePayloadKind::Unknown,
+                storage_payload::Message::TempProposalPayload(_) => {
+                    StoragePayloadKind::TempProposalPayload
+                }
+                storage_payload::Message::RefundAgent(_) => StoragePayloadKind::RefundAgent,
             })
             .unwrap_or_default()
     }
@@ -42,6 +64,18 @@ pub enum PersistableNetworkPayloadKind {
     SignedWitness,
     Unknown,
 }
+impl PersistableNetworkPayloadKind {
+    pub fn required_capability(&self) -> Option<Capability> {
+        match self {
+            Self::AccountAgeWitness => Some(Capability::AccountAgeWitness),
+            Self::TradeStatistics2 => Some(Capability::TradeStatistics2),
+            Self::ProposalPayload => Some(Capability::Proposal),
+            Self::BlindVotePayload => Some(Capability::BlindVote),
+            Self::SignedWitness => Some(Capability::SignedAccountAgeWitness),
+            Self::Unknown => None,
+        }
+    }
+}
 impl Default for PersistableNetworkPayloadKind {
     fn default() -> Self {
         Self::Unknown
@@ -53,10 +87,21 @@ impl From<&PersistableNetworkPayload> for PersistableNetworkPayloadKind {
             .message
             .as_ref()
             .map(|m| match m {
+                persistable_network_payload::Message::AccountAgeWitness(_) => {
+                    PersistableNetworkPayloadKind::AccountAgeWitness
+                }
                 persistable_network_payload::Message::TradeStatistics2(_) => {
                     PersistableNetworkPayloadKind::TradeStatistics2
                 }
-                _ => PersistableNetworkPayloadKind::Unknown,
+                persistable_network_payload::Message::ProposalPayload(_) => {
+                    PersistableNetworkPayloadKind::ProposalPayload
+                }
+                persistable_network_payload::Message::BlindVotePayload(_) => {
+                    PersistableNetworkPayloadKind::BlindVotePayload
+                }
+                persistable_network_payload::Message::SignedWitness(_) => {
+                    PersistableNetworkPayloadKind::SignedWitness
+                }
             })
             .unwrap_or_default()
     }
@@ -0,0 +1,36 @@
+use super::payload::{persistable_envelope, PersistableEnvelope};
+use crate::error::Error;
+use prost::Message;
+use std::{
+    fs::{self, File},
+    io::{ErrorKind, Read, Write},
+    path::Path,
+};
+
+// Bisq writes its stores as a single length delimited PersistableEnvelope
+pub fn read(path: &Path) -> Result<Option<persistable_envelope::Message>, Error> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(ref e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+    Ok(PersistableEnvelope::decode_length_delimited(buf)?.message)
+}
+
+pub fn write(path: &Path, message: persistable_envelope::Message) -> Result<(), Error> {
+    let envelope = PersistableEnvelope {
+        message: Some(message),
+    };
+    let mut serialized = Vec::with_capacity(envelope.encoded_len() + 10);
+    envelope.encode_length_delimited(&mut serialized)?;
+
+    // Write to a temp file first so that a crash can't leave a truncated store behind
+    let tmp_path = path.with_extension("tmp");
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(&serialized)?;
+    file.sync_all()?;
+    fs::rename(tmp_path, path)?;
+    Ok(())
+}
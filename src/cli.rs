@@ -6,11 +6,10 @@ use crate::{
     daemon::{self, DaemonConfig},
     domain::{currency::Currency, market::Market},
 };
-use clap::{clap_app, crate_version, App, ArgMatches};
+use clap::{clap_app, crate_version, App, Arg, ArgMatches, SubCommand};
 use env_logger::Env;
 use log::Level;
 use query::*;
-use reqwest;
 use std::{collections::HashMap, env, path::PathBuf, str::FromStr};
 
 fn app() -> App<'static, 'static> {
@@ -18,18 +17,12 @@ fn app() -> App<'static, 'static> {
         (version: crate_version!())
         (@setting VersionlessSubcommands)
         (@setting SubcommandRequiredElseHelp)
-        (@subcommand daemon =>
-         (about: "Runs the risq p2p node")
-         (visible_alias: "d")
+        (@subcommand replay =>
+         (about: "Replays a recording made with --record without connecting to the network")
          (@arg API_PORT: --("api-port") default_value("7477") {port} "API port")
          (@arg LOG_LEVEL: -l --("log-level") default_value("info") {level} "(error|warn|info|debug|trace)")
          (@arg NETWORK: -n --network default_value("BtcMainnet") {network} "(BtcRegtest|BtcTestnet|BtcMainnet)")
-         (@arg P2P_PORT: -p --("p2p-port") default_value("5000") {port} "Port of p2p node")
-         (@arg FORCE_SEED: --("force-seed") +takes_value {node_address} "Force usage of seed node")
-         (@arg NO_TOR: --("no-tor") "Disable tor / run on localhost")
-         (@arg TOR_CONTROL_PORT: --("tor-control-port") default_value("9051") {port} "Tor Control port")
-         (@arg TOR_HIDDEN_SERVICE_PORT: --("tor-hidden-service-port") default_value("9999") {port} "Public port of the hidden service")
-         (@arg TOR_SOCKS_PORT: --("tor-socks-port") default_value("9050") {port} "Tor SOCKSPort")
+         (@arg RECORDING: +required {file} "Recording to replay")
         )
         (@subcommand offers =>
          (about: "Subcommand to interact with offers")
@@ -38,23 +31,149 @@ fn app() -> App<'static, 'static> {
         )
     );
 
+    let app = app
+        .subcommand(
+            node_args(SubCommand::with_name("daemon"), "9999")
+                .about("Runs the risq p2p node")
+                .visible_alias("d")
+                .arg(
+                    Arg::with_name("FORCE_SEED")
+                        .long("force-seed")
+                        .takes_value(true)
+                        .validator(node_address)
+                        .help("Force usage of seed node"),
+                ),
+        )
+        .subcommand(
+            node_args(SubCommand::with_name("seed"), "8000")
+                .about("Runs the risq p2p node as a bisq seed node"),
+        );
     let app = add_checker_cmd(app);
+    let app = add_inspect_cmd(app);
     add_dummy_seed_cmd(app)
 }
 
 pub fn run() {
     let matches = app().get_matches();
     match matches.subcommand() {
-        ("daemon", Some(matches)) => daemon(matches),
+        ("daemon", Some(matches)) => daemon::run(daemon_config(matches, false)),
+        ("seed", Some(matches)) => daemon::run(daemon_config(matches, true)),
+        ("replay", Some(matches)) => replay(matches),
         ("offers", Some(matches)) => offers(matches),
         #[cfg(feature = "checker")]
         ("check-node", Some(matches)) => check_node(matches),
+        #[cfg(feature = "inspect")]
+        ("inspect", Some(matches)) => inspect(matches),
         #[cfg(feature = "dummy-seed")]
         ("dummy-seed", Some(matches)) => dummy_seed(matches),
         _ => unreachable!(),
     }
 }
 
+// Shared by the daemon and seed subcommands, which only differ in their defaults
+fn node_args(
+    cmd: App<'static, 'static>,
+    hidden_service_port: &'static str,
+) -> App<'static, 'static> {
+    cmd.arg(
+        Arg::with_name("API_PORT")
+            .long("api-port")
+            .validator(port)
+            .default_value("7477")
+            .help("API port"),
+    )
+    .arg(
+        Arg::with_name("LOG_LEVEL")
+            .short("l")
+            .long("log-level")
+            .validator(level)
+            .default_value("info")
+            .help("(error|warn|info|debug|trace)"),
+    )
+    .arg(
+        Arg::with_name("NETWORK")
+            .short("n")
+            .long("network")
+            .validator(network)
+            .default_value("BtcMainnet")
+            .help("(BtcRegtest|BtcTestnet|BtcMainnet)"),
+    )
+    .arg(
+        Arg::with_name("P2P_PORT")
+            .short("p")
+            .long("p2p-port")
+            .validator(port)
+            .default_value("5000")
+            .help("Port of p2p node"),
+    )
+    .arg(
+        Arg::with_name("SEED")
+            .long("seed")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .validator(node_address)
+            .help("Additional seed node to connect to"),
+    )
+    .arg(
+        Arg::with_name("BISQ_DATA_DIR")
+            .long("bisq-data-dir")
+            .takes_value(true)
+            .validator(dir)
+            .help("Import the data stores of an existing bisq installation"),
+    )
+    .arg(
+        Arg::with_name("NO_TOR")
+            .long("no-tor")
+            .help("Disable tor / run on localhost"),
+    )
+    .arg(
+        Arg::with_name("TOR_CONTROL_PORT")
+            .long("tor-control-port")
+            .validator(port)
+            .default_value("9051")
+            .help("Tor Control port"),
+    )
+    .arg(
+        Arg::with_name("TOR_CONTROL_PASSWORD")
+            .long("tor-control-password")
+            .takes_value(true)
+            .help("Password for HashedControlPassword auth (or set RISQ_TOR_CONTROL_PASSWORD)"),
+    )
+    .arg(
+        Arg::with_name("TOR_HIDDEN_SERVICE_PORT")
+            .long("tor-hidden-service-port")
+            .validator(port)
+            .default_value(hidden_service_port)
+            .help("Public port of the hidden service"),
+    )
+    .arg(
+        Arg::with_name("TOR_SOCKS_PORT")
+            .long("tor-socks-port")
+            .validator(port)
+            .default_value("9050")
+            .help("Tor SOCKSPort"),
+    )
+    .arg(
+        Arg::with_name("MANAGED_TOR")
+            .long("managed-tor")
+            .conflicts_with("NO_TOR")
+            .help("Launch and supervise a tor process configured in RISQ_HOME"),
+    )
+    .arg(
+        Arg::with_name("TOR_BINARY")
+            .long("tor-binary")
+            .default_value("tor")
+            .help("Tor executable used with --managed-tor"),
+    )
+    .arg(
+        Arg::with_name("RECORD")
+            .long("record")
+            .takes_value(true)
+            .help("Record all p2p messages to the given file"),
+    )
+}
+
 fn network(network: String) -> Result<(), String> {
     match BaseCurrencyNetwork::from_str(&network) {
         Err(_) => Err("(BtcMainnet|BtcTestnet|BtcRegtest)".into()),
@@ -85,7 +204,12 @@ fn level(level: String) -> Result<(), String> {
         Ok(_) => Ok(()),
     }
 }
-#[cfg(feature = "dummy-seed")]
+fn dir(dir: String) -> Result<(), String> {
+    if !PathBuf::from(&dir).is_dir() {
+        return Err(format!("'{}' is not a directory", dir));
+    }
+    Ok(())
+}
 fn file(file: String) -> Result<(), String> {
     use std::path::Path;
     let path = Path::new(&file);
@@ -97,8 +221,9 @@ fn file(file: String) -> Result<(), String> {
 }
 
 const RISQ_HOME_VAR: &str = "RISQ_HOME";
+const TOR_CONTROL_PASSWORD_VAR: &str = "RISQ_TOR_CONTROL_PASSWORD";
 
-fn daemon(matches: &ArgMatches) {
+fn daemon_config(matches: &ArgMatches, seed_node: bool) -> DaemonConfig {
     let risq_home = env::var_os(RISQ_HOME_VAR)
         .map(PathBuf::from)
         .unwrap_or_else(|| {
@@ -114,9 +239,18 @@ fn daemon(matches: &ArgMatches) {
 
     init_log(matches);
 
+    let bisq_data_dir = matches.value_of("BISQ_DATA_DIR").map(PathBuf::from);
     let force_seed = matches
         .value_of("FORCE_SEED")
         .and_then(|seed| NodeAddress::from_str(&seed).ok());
+    let seeds = matches
+        .values_of("SEED")
+        .map(|seeds| {
+            seeds
+                .filter_map(|seed| NodeAddress::from_str(&seed).ok())
+                .collect()
+        })
+        .unwrap_or_default();
 
     let (tor_proxy_port, tor_control_port, hidden_service_port) = if tor_active {
         (
@@ -139,16 +273,40 @@ fn daemon(matches: &ArgMatches) {
     } else {
         (None, None, None)
     };
-    daemon::run(DaemonConfig {
+    let tor_binary = if tor_active && matches.is_present("MANAGED_TOR") {
+        matches.value_of("TOR_BINARY").map(PathBuf::from)
+    } else {
+        None
+    };
+    let record = matches.value_of("RECORD").map(PathBuf::from);
+    let tor_control_password = matches
+        .value_of("TOR_CONTROL_PASSWORD")
+        .map(String::from)
+        .or_else(|| env::var(TOR_CONTROL_PASSWORD_VAR).ok());
+    DaemonConfig {
         api_port,
         server_port,
         network,
         force_seed,
+        seeds,
+        seed_node,
         risq_home,
+        bisq_data_dir,
         tor_control_port,
+        tor_control_password,
         tor_proxy_port,
         hidden_service_port,
-    });
+        tor_binary,
+        record,
+    }
+}
+
+fn replay(matches: &ArgMatches) {
+    init_log(matches);
+    let api_port = matches.value_of("API_PORT").unwrap().parse().unwrap();
+    let network: BaseCurrencyNetwork = matches.value_of("NETWORK").unwrap().parse().unwrap();
+    let recording = PathBuf::from(matches.value_of("RECORDING").unwrap());
+    daemon::replay(&recording, network, api_port)
 }
 
 fn offers(matches: &ArgMatches) {
@@ -180,7 +338,6 @@ fn add_checker_cmd(app: App<'static, 'static>) -> App<'static, 'static> {
 }
 #[cfg(feature = "checker")]
 fn add_checker_cmd(app: App<'static, 'static>) -> App<'static, 'static> {
-    use clap::{Arg, SubCommand};
     app.subcommand(
         SubCommand::with_name("check-node")
             .about("Send a ping to a node. Used for monitoring.")
@@ -208,14 +365,49 @@ fn add_checker_cmd(app: App<'static, 'static>) -> App<'static, 'static> {
     )
 }
 
+#[cfg(not(feature = "inspect"))]
+fn add_inspect_cmd(app: App<'static, 'static>) -> App<'static, 'static> {
+    app
+}
+#[cfg(feature = "inspect")]
+fn add_inspect_cmd(app: App<'static, 'static>) -> App<'static, 'static> {
+    use crate::inspect::{Encoding, Kind};
+
+    app.subcommand(
+        SubCommand::with_name("inspect")
+            .about("Decode a bisq protobuf message and print it as json")
+            .arg(
+                Arg::with_name("TYPE")
+                    .long("type")
+                    .short("t")
+                    .validator(|t| Kind::from_str(&t).map(|_| ()))
+                    .default_value("network-envelope")
+                    .help("(network-envelope|storage-payload|persistable-envelope|protected-storage-entry)"),
+            )
+            .arg(
+                Arg::with_name("ENCODING")
+                    .long("encoding")
+                    .short("e")
+                    .validator(|e| Encoding::from_str(&e).map(|_| ()))
+                    .default_value("hex")
+                    .help("(hex|base64|raw)"),
+            )
+            .arg(
+                Arg::with_name("INPUT")
+                    .index(1)
+                    .required(true)
+                    .help("Encoded message, or the path of a file for --encoding raw"),
+            )
+            .after_help("Returns exit code 0 on success, 1 if the input couldn't be decoded."),
+    )
+}
+
 #[cfg(not(feature = "dummy-seed"))]
 fn add_dummy_seed_cmd(app: App<'static, 'static>) -> App<'static, 'static> {
     app
 }
 #[cfg(feature = "dummy-seed")]
 fn add_dummy_seed_cmd(app: App<'static, 'static>) -> App<'static, 'static> {
-    use clap::{Arg, SubCommand};
-
     app.subcommand(
         SubCommand::with_name("dummy-seed")
             .about("Start a seed node used for testing")
@@ -251,6 +443,20 @@ fn check_node(matches: &ArgMatches) {
     checker::check_node(network, NodeAddress { host_name, port }, socks_port);
 }
 
+#[cfg(feature = "inspect")]
+fn inspect(matches: &ArgMatches) {
+    use crate::inspect;
+    use std::process;
+
+    let kind = matches.value_of("TYPE").unwrap().parse().unwrap();
+    let encoding = matches.value_of("ENCODING").unwrap().parse().unwrap();
+    let input = matches.value_of("INPUT").unwrap();
+    if let Err(e) = inspect::inspect(kind, encoding, input) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
 #[cfg(feature = "dummy-seed")]
 fn dummy_seed(matches: &ArgMatches) {
     use crate::dummy_seed;
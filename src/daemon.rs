@@ -1,31 +1,62 @@
+mod block_sync;
 mod convert;
 mod data_router;
+mod state_monitor;
+mod store;
 
 use crate::{
     api,
-    bisq::{constants::BaseCurrencyNetwork, NodeAddress},
-    domain::{offer::*, price_feed::PriceFeed, statistics::StatsCache},
+    bisq::{constants::BaseCurrencyNetwork, payload::PrivateNotificationMessage, NodeAddress},
+    domain::{
+        account_age::AccountAgeCache,
+        alert::AlertCache,
+        dao::{BsqBlocks, DaoStateHashes, GovernanceStore},
+        dispute_agent::DisputeAgentRegistry,
+        filter::FilterCache,
+        offer::*,
+        price_feed::PriceFeed,
+        signed_witness::SignedWitnessCache,
+        statistics::StatsCache,
+    },
     p2p::{
-        dispatch::ActorDispatcher, server, Bootstrap, BootstrapState, Broadcaster, Peers, Status,
-        TorConfig,
+        dispatch::{self, ActorDispatcher, Dispatch, Dispatcher},
+        recorder, server, Bootstrap, BootstrapState, Broadcaster, Peers, Status, TorConfig,
+        TorProcess, TorProcessConfig,
     },
     prelude::*,
 };
+use block_sync::*;
 use data_router::*;
-use std::{fs, path::PathBuf};
+use state_monitor::*;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+use store::DataStore;
 
 pub struct DaemonConfig {
     pub api_port: u16,
     pub server_port: u16,
     pub network: BaseCurrencyNetwork,
     pub force_seed: Option<NodeAddress>,
+    pub seeds: Vec<NodeAddress>,
+    pub seed_node: bool,
     pub risq_home: PathBuf,
+    pub bisq_data_dir: Option<PathBuf>,
     pub tor_control_port: Option<u16>,
+    pub tor_control_password: Option<String>,
     pub tor_proxy_port: Option<u16>,
     pub hidden_service_port: Option<u16>,
+    pub tor_binary: Option<PathBuf>,
+    pub record: Option<PathBuf>,
 }
 
 const SERIVCE_PRIVATE_KEY_PATH: &str = "tor/service.key";
+const SEED_NODE_PRIVATE_KEY_PATH: &str = "tor/seed_node.key";
+const TOR_DIR: &str = "tor";
+const DB_DIR: &str = "db";
+const PEER_LIST_FILE: &str = "PeerList";
 
 pub fn run(
     DaemonConfig {
@@ -33,48 +64,141 @@ pub fn run(
         server_port,
         network,
         force_seed,
+        seeds,
+        seed_node,
         risq_home,
+        bisq_data_dir,
         tor_control_port,
+        tor_control_password,
         tor_proxy_port,
         hidden_service_port,
+        tor_binary,
+        record,
     }: DaemonConfig,
 ) {
-    let private_key_path = risq_home.join(SERIVCE_PRIVATE_KEY_PATH);
+    if let Some(record) = record {
+        if let Err(e) = recorder::start_recording(&record) {
+            error!("Couldn't open recording {}: {:?}", record.display(), e);
+            return;
+        }
+    }
+    let private_key_path = risq_home.join(if seed_node {
+        SEED_NODE_PRIVATE_KEY_PATH
+    } else {
+        SERIVCE_PRIVATE_KEY_PATH
+    });
     fs::create_dir_all(private_key_path.parent().unwrap()).expect("Couldn't create risq dir");
+    if let Some(bisq_data_dir) = bisq_data_dir {
+        import_bisq_data_dir(&bisq_data_dir, &risq_home, network);
+    }
+    let db_dir = db_dir(&risq_home, network);
+    let peer_list_path = db_dir.join(PEER_LIST_FILE);
     let tor_config = match (tor_control_port, hidden_service_port) {
         (Some(tc_port), Some(hidden_service_port)) => Some(TorConfig {
             hidden_service_port,
             tc_port,
+            control_password: tor_control_password,
             private_key_path,
         }),
         _ => None,
     };
 
+    let tor_process = match (tor_binary, tor_control_port, tor_proxy_port) {
+        (Some(tor_binary), Some(control_port), Some(socks_port)) => {
+            match TorProcess::launch(TorProcessConfig {
+                tor_binary,
+                tor_dir: risq_home.join(TOR_DIR),
+                control_port,
+                socks_port,
+            }) {
+                Ok(tor_process) => Some(tor_process),
+                Err(e) => {
+                    error!("Couldn't launch tor: {:?}", e);
+                    return;
+                }
+            }
+        }
+        _ => None,
+    };
+
     let sys = System::new("risq");
+    let supervised_tor = tor_process.clone();
 
     // Domain Thread
     let price_feed = PriceFeed::start(tor_proxy_port);
-    let offer_book = OfferBook::start(price_feed);
+    let filter_cache = FilterCache::default();
+    let alert_cache = AlertCache::default();
+    let account_ages = AccountAgeCache::default();
+    let signed_witnesses = SignedWitnessCache::new(network);
+    let governance = GovernanceStore::new(network);
+    let dao_state_hashes = DaoStateHashes::default();
+    let bsq_blocks = BsqBlocks::default();
+    let offer_book = OfferBook::start(price_feed, filter_cache.clone());
+    let dispute_agents = DisputeAgentRegistry::start();
 
     Arbiter::new().exec_fn(move || {
         // Daemon Thread
         let stats_cache = StatsCache::new();
         let broadcaster = Broadcaster::start();
-        let data_router =
-            DataRouter::start(offer_book.clone(), broadcaster.clone(), stats_cache.clone());
+        let bootstrap_state = BootstrapState::init();
+        let p2p_status = Status::new(bootstrap_state.clone());
+        let block_store = DataStore::new(db_dir.clone());
+        let store = DataStore::new(db_dir);
+        let persisted = store.load();
+        let known_keys = persisted.excluded_keys();
+        let data_router = DataRouter::start(
+            network,
+            offer_book.clone(),
+            filter_cache.clone(),
+            alert_cache.clone(),
+            account_ages.clone(),
+            signed_witnesses.clone(),
+            governance.clone(),
+            dispute_agents.clone(),
+            broadcaster.clone(),
+            p2p_status.clone(),
+            stats_cache.clone(),
+            store,
+            persisted,
+        );
 
         Arbiter::new().exec_fn(move || {
             // P2P Thread
-            let dispatcher = ActorDispatcher::<DataRouter, DataRouterDispatch>::new(data_router);
-            let bootstrap_state = BootstrapState::init();
-
-            let p2p_status = Status::new(bootstrap_state.clone());
+            let state_monitor = DaoStateMonitor::start(
+                dao_state_hashes.clone(),
+                bsq_blocks.clone(),
+                broadcaster.clone(),
+                p2p_status.clone(),
+            );
+            let block_sync = BsqBlockSync::start(
+                network,
+                bsq_blocks.clone(),
+                broadcaster.clone(),
+                p2p_status.clone(),
+                block_store,
+            );
+            let dispatcher = dispatch::chain(
+                ActorDispatcher::<DataRouter, DataRouterDispatch>::new(data_router.clone()),
+            )
+            .forward_to(ActorDispatcher::<DataRouter, GetDataRequest>::new(
+                data_router.clone(),
+            ))
+            .forward_to(ActorDispatcher::<DataRouter, PrivateNotificationMessage>::new(data_router))
+            .forward_to(ActorDispatcher::<DaoStateMonitor, StateHashDispatch>::new(
+                state_monitor,
+            ))
+            .forward_to(ActorDispatcher::<BsqBlockSync, BlockSyncDispatch>::new(
+                block_sync,
+            ));
             let peers = Peers::start(
                 network,
                 broadcaster,
                 p2p_status.clone(),
+                filter_cache.clone(),
                 dispatcher.clone(),
                 tor_proxy_port,
+                Some(peer_list_path),
+                seed_node,
             );
             let bootstrap = Bootstrap::start(
                 network,
@@ -83,13 +207,172 @@ pub fn run(
                 dispatcher,
                 tor_proxy_port,
                 force_seed,
+                seeds,
+                known_keys,
+                seed_node,
             );
-            server::start(server_port, peers, Some(bootstrap), tor_config);
+            let server = server::start(
+                server_port,
+                peers,
+                Some(bootstrap),
+                p2p_status.clone(),
+                tor_config,
+            );
+            if let Some(tor_process) = supervised_tor {
+                tor_process.supervise(server.recipient());
+            }
 
             // Api Thread
-            let _ = api::listen(api_port, offer_book, p2p_status, stats_cache);
+            let _ = api::listen(
+                api_port,
+                offer_book,
+                filter_cache,
+                alert_cache,
+                account_ages,
+                signed_witnesses,
+                governance,
+                dao_state_hashes,
+                bsq_blocks,
+                dispute_agents,
+                p2p_status,
+                stats_cache,
+            );
         });
     });
 
     let _ = sys.run();
+    recorder::stop_recording();
+    if let Some(tor_process) = tor_process {
+        tor_process.shutdown();
+    }
+}
+
+// Merges the data stores of a bisq desktop installation into risq's db
+pub fn import_bisq_data_dir(bisq_data_dir: &Path, risq_home: &Path, network: BaseCurrencyNetwork) {
+    let bisq_db_dir = bisq_data_dir.join(network.data_dir_name()).join(DB_DIR);
+    if !bisq_db_dir.is_dir() {
+        warn!("No bisq db found at {}", bisq_db_dir.display());
+        return;
+    }
+    DataStore::new(db_dir(risq_home, network)).import(&DataStore::new(bisq_db_dir));
+}
+
+// Feeds the inbound messages of a recording through the domain actors without
+// touching the network. The api keeps serving the resulting state afterwards.
+pub fn replay(recording: &Path, network: BaseCurrencyNetwork, api_port: u16) {
+    let records = match recorder::read_recording(recording) {
+        Ok(records) => records,
+        Err(e) => {
+            error!("Couldn't read recording {}: {:?}", recording.display(), e);
+            return;
+        }
+    };
+    let store_dir = env::temp_dir().join(format!("risq-replay-{}", process::id()));
+    fs::create_dir_all(&store_dir).expect("Couldn't create replay dir");
+
+    let sys = System::new("risq");
+    let filter_cache = FilterCache::default();
+    let alert_cache = AlertCache::default();
+    let account_ages = AccountAgeCache::default();
+    let signed_witnesses = SignedWitnessCache::new(network);
+    let governance = GovernanceStore::new(network);
+    let dao_state_hashes = DaoStateHashes::default();
+    let bsq_blocks = BsqBlocks::default();
+    let offer_book = OfferBook::start(PriceFeed::offline(), filter_cache.clone());
+    let dispute_agents = DisputeAgentRegistry::start();
+    let stats_cache = StatsCache::new();
+    let bootstrap_state = BootstrapState::init();
+    let p2p_status = Status::new(bootstrap_state.clone());
+    let broadcaster = Broadcaster::start();
+    let data_router = DataRouter::start(
+        network,
+        offer_book.clone(),
+        filter_cache.clone(),
+        alert_cache.clone(),
+        account_ages.clone(),
+        signed_witnesses.clone(),
+        governance.clone(),
+        dispute_agents.clone(),
+        broadcaster.clone(),
+        p2p_status.clone(),
+        stats_cache.clone(),
+        DataStore::new(store_dir.clone()),
+        Default::default(),
+    );
+    let dispatcher = dispatch::chain(ActorDispatcher::<DataRouter, DataRouterDispatch>::new(
+        data_router.clone(),
+    ))
+    .forward_to(ActorDispatcher::<DataRouter, GetDataRequest>::new(
+        data_router.clone(),
+    ))
+    .forward_to(ActorDispatcher::<DataRouter, PrivateNotificationMessage>::new(data_router))
+    .forward_to(ActorDispatcher::<DaoStateMonitor, StateHashDispatch>::new(
+        DaoStateMonitor::start(
+            dao_state_hashes.clone(),
+            bsq_blocks.clone(),
+            broadcaster.clone(),
+            p2p_status.clone(),
+        ),
+    ))
+    .forward_to(ActorDispatcher::<BsqBlockSync, BlockSyncDispatch>::new(
+        BsqBlockSync::start(
+            network,
+            bsq_blocks.clone(),
+            broadcaster,
+            p2p_status.clone(),
+            DataStore::new(store_dir.clone()),
+        ),
+    ));
+
+    let mut replayed = 0;
+    for record in records.into_iter().filter(|record| record.inbound) {
+        let conn = match record.connection_id.parse() {
+            Ok(conn) => conn,
+            Err(_) => {
+                warn!("Skipping record with invalid id {}", record.connection_id);
+                continue;
+            }
+        };
+        if let Some(msg) = record.envelope.message {
+            if let Dispatch::Retained(msg) = dispatcher.dispatch(conn, msg) {
+                debug!("Not replaying message: {:?}", msg);
+            } else {
+                replayed += 1;
+            }
+        }
+    }
+    info!(
+        "Replayed {} messages from {}",
+        replayed,
+        recording.display()
+    );
+
+    *bootstrap_state
+        .write()
+        .expect("Corrupted lock in bootstrap state") = BootstrapState::Bootstrapped {
+        seed: None,
+        failed_attempts: 0,
+    };
+    let _ = api::listen(
+        api_port,
+        offer_book,
+        filter_cache,
+        alert_cache,
+        account_ages,
+        signed_witnesses,
+        governance,
+        dao_state_hashes,
+        bsq_blocks,
+        dispute_agents,
+        p2p_status,
+        stats_cache,
+    );
+    let _ = sys.run();
+    let _ = fs::remove_dir_all(&store_dir);
+}
+
+fn db_dir(risq_home: &Path, network: BaseCurrencyNetwork) -> PathBuf {
+    let db_dir = risq_home.join(network.data_dir_name()).join(DB_DIR);
+    fs::create_dir_all(&db_dir).expect("Couldn't create db dir");
+    db_dir
 }
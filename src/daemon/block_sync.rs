@@ -0,0 +1,296 @@
+use super::{
+    convert,
+    store::{DataStore, Persist, StoreWriter},
+};
+use crate::{
+    bisq::{
+        constants::{seed_nodes, BaseCurrencyNetwork, LOCAL_CAPABILITIES},
+        payload::*,
+    },
+    domain::dao::{BsqBlock, BsqBlocks},
+    p2p::{
+        dispatch::Receive,
+        message::{Broadcast, Direct},
+        BootstrapState, Broadcaster, ConnectionId, Status,
+    },
+    prelude::*,
+};
+use std::time::{Duration, Instant};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+// Catches up on blocks whose broadcast we missed
+const SYNC_INTERVAL: Duration = Duration::from_secs(10 * 60);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2 * 60);
+const PERSIST_INTERVAL: Duration = Duration::from_secs(10 * 60);
+// Number of blocks dropped when a block from the seed doesn't connect to the local chain
+const REORG_ROLLBACK: usize = 10;
+
+enum AddBlock {
+    Appended,
+    Known,
+    Unconnected,
+}
+
+// Syncs the raw BSQ blocks from full DAO nodes the same way bisq's lite nodes do.
+// Blocks are trusted as delivered, only their linking to the local chain is checked.
+pub struct BsqBlockSync {
+    network: BaseCurrencyNetwork,
+    bsq_blocks: BsqBlocks,
+    broadcaster: Addr<Broadcaster>,
+    status: Status,
+    store: DataStore,
+    store_writer: Addr<StoreWriter>,
+    store_dirty: bool,
+    chain: Vec<BaseBlock>,
+    // Nonce, requested height, the seed asked and when the request was sent
+    pending_request: Option<(i32, i32, ConnectionId, Instant)>,
+    synced_at: Option<Instant>,
+}
+impl Actor for BsqBlockSync {
+    type Context = Context<Self>;
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.run_interval(CHECK_INTERVAL, |sync, _ctx| {
+            if sync
+                .synced_at
+                .map(|synced_at| synced_at.elapsed() > SYNC_INTERVAL)
+                .unwrap_or(true)
+            {
+                sync.request_blocks()
+            }
+        });
+        ctx.run_interval(PERSIST_INTERVAL, |sync, _ctx| sync.persist());
+    }
+    // The writer might already be gone when shutting down, so flush right here
+    fn stopping(&mut self, _ctx: &mut Self::Context) -> Running {
+        if let Some(persist) = self.take_changed_blocks() {
+            self.store.persist(persist);
+        }
+        Running::Stop
+    }
+}
+impl BsqBlockSync {
+    pub fn start(
+        network: BaseCurrencyNetwork,
+        bsq_blocks: BsqBlocks,
+        broadcaster: Addr<Broadcaster>,
+        status: Status,
+        store: DataStore,
+    ) -> Addr<BsqBlockSync> {
+        let mut sync = BsqBlockSync {
+            network,
+            bsq_blocks,
+            broadcaster,
+            status,
+            chain: Vec::new(),
+            store_dirty: false,
+            pending_request: None,
+            synced_at: None,
+            store_writer: StoreWriter::start(store.clone()),
+            store,
+        };
+        for block in sync.store.load_bsq_blocks() {
+            if let AddBlock::Unconnected = sync.add_block(block, true) {
+                warn!("Persisted bsq blocks are not connected, syncing from scratch");
+                sync.roll_back(sync.chain.len());
+                break;
+            }
+        }
+        sync.store_dirty = false;
+        sync.start()
+    }
+
+    fn request_blocks(&mut self) {
+        match self.pending_request {
+            Some((_, _, _, sent_at)) if sent_at.elapsed() < REQUEST_TIMEOUT => return,
+            _ => (),
+        }
+        match self.status.bootstrap_state() {
+            BootstrapState::Bootstrapped { .. } => (),
+            _ => return,
+        }
+        // Anyone seeing the nonce could answer, so only a seed is ever asked
+        let seed = match self.seed_connection() {
+            Some(seed) => seed,
+            None => return,
+        };
+        let nonce = gen_nonce();
+        let from_block_height = self.chain.last().map(|tip| tip.height + 1).unwrap_or(0);
+        self.pending_request = Some((nonce, from_block_height, seed, Instant::now()));
+        let request = GetBlocksRequest {
+            from_block_height,
+            nonce,
+            sender_node_address: None,
+            supported_capabilities: LOCAL_CAPABILITIES.clone(),
+        };
+        debug!("Requesting bsq blocks from {}", request.from_block_height);
+        arbiter_spawn!(self.broadcaster.send(Direct(request, seed)));
+    }
+
+    // Seed nodes are full DAO nodes, the one we bootstrapped from is preferred
+    fn seed_connection(&self) -> Option<ConnectionId> {
+        let connections = self.status.connections();
+        let connected = |seed: &NodeAddress| {
+            connections
+                .iter()
+                .find(|(_, status)| status.addr.as_ref() == Some(seed))
+                .map(|(id, _)| *id)
+        };
+        self.status
+            .bootstrap_state()
+            .seed()
+            .and_then(connected)
+            .or_else(|| seed_nodes(self.network).iter().find_map(connected))
+    }
+
+    // Only blocks requested from height 0 may start the chain, as the first
+    // one has to be the block holding the genesis tx
+    fn add_block(&mut self, block: BaseBlock, starts_chain: bool) -> AddBlock {
+        let domain_block = match convert::bsq_block(&block) {
+            Some(domain_block) => domain_block,
+            None => return AddBlock::Unconnected,
+        };
+        let tip = match self.chain.last() {
+            Some(tip) => tip,
+            None if starts_chain => {
+                self.append(block, domain_block);
+                return AddBlock::Appended;
+            }
+            None => return AddBlock::Unconnected,
+        };
+        if block.height == tip.height + 1 && block.previous_block_hash == tip.hash {
+            self.append(block, domain_block);
+            return AddBlock::Appended;
+        }
+        if block.height > tip.height || block.height < self.chain[0].height {
+            return AddBlock::Unconnected;
+        }
+        let offset = (block.height - self.chain[0].height) as usize;
+        if self.chain[offset].hash == block.hash {
+            AddBlock::Known
+        } else if offset > 0 && self.chain[offset - 1].hash == block.previous_block_hash {
+            info!("Bsq chain reorg at height {}", block.height);
+            self.roll_back(self.chain.len() - offset);
+            self.append(block, domain_block);
+            AddBlock::Appended
+        } else {
+            AddBlock::Unconnected
+        }
+    }
+
+    fn append(&mut self, block: BaseBlock, domain_block: BsqBlock) {
+        self.bsq_blocks.push(domain_block);
+        self.chain.push(block);
+        self.store_dirty = true;
+    }
+
+    fn roll_back(&mut self, blocks: usize) {
+        let keep = self.chain.len().saturating_sub(blocks);
+        self.chain.truncate(keep);
+        match self.chain.last() {
+            Some(tip) => self.bsq_blocks.truncate(tip.height as u32 + 1),
+            None => self.bsq_blocks.truncate(0),
+        }
+        self.store_dirty = true;
+    }
+
+    fn take_changed_blocks(&mut self) -> Option<Persist> {
+        if !self.store_dirty {
+            return None;
+        }
+        self.store_dirty = false;
+        Some(Persist::BsqBlocks(self.chain.clone()))
+    }
+
+    fn persist(&mut self) {
+        if let Some(persist) = self.take_changed_blocks() {
+            self.store_writer.do_send(persist);
+        }
+    }
+}
+
+pub enum BlockSyncDispatch {
+    Response(GetBlocksResponse),
+    NewBlock(NewBlockBroadcastMessage),
+}
+
+impl Handler<Receive<BlockSyncDispatch>> for BsqBlockSync {
+    type Result = ();
+    fn handle(
+        &mut self,
+        Receive(origin, msg): Receive<BlockSyncDispatch>,
+        _ctx: &mut Self::Context,
+    ) {
+        match msg {
+            BlockSyncDispatch::Response(response) => {
+                let starts_chain = match self.pending_request {
+                    Some((nonce, from_height, seed, _))
+                        if nonce == response.request_nonce && seed == origin =>
+                    {
+                        from_height == 0
+                    }
+                    _ => return,
+                };
+                self.pending_request = None;
+                let mut appended = 0;
+                let mut rolled_back = false;
+                for block in response.raw_blocks {
+                    match self.add_block(block, starts_chain) {
+                        AddBlock::Appended => appended += 1,
+                        AddBlock::Known => (),
+                        AddBlock::Unconnected => {
+                            self.roll_back(REORG_ROLLBACK);
+                            rolled_back = true;
+                            break;
+                        }
+                    }
+                }
+                if appended > 0 {
+                    info!(
+                        "Synced {} bsq blocks, chain height {}",
+                        appended,
+                        self.chain.last().map(|tip| tip.height).unwrap_or_default()
+                    );
+                }
+                // Full nodes limit the number of blocks per response
+                if appended > 0 || rolled_back {
+                    self.request_blocks();
+                } else {
+                    self.synced_at = Some(Instant::now());
+                    self.persist();
+                }
+            }
+            BlockSyncDispatch::NewBlock(msg) => {
+                let block = match msg.raw_block.clone() {
+                    Some(block) => block,
+                    None => return,
+                };
+                let height = block.height;
+                match self.add_block(block, false) {
+                    AddBlock::Appended => {
+                        debug!("Received new bsq block {}", height);
+                        arbiter_spawn!(self.broadcaster.send(Broadcast(msg, Some(origin))));
+                    }
+                    AddBlock::Known => (),
+                    // Either we missed some blocks or our tip got reorged away. Any peer can
+                    // broadcast, so only the response of the seed may cause a rollback.
+                    AddBlock::Unconnected => self.request_blocks(),
+                }
+            }
+        }
+    }
+}
+
+impl PayloadExtractor for BlockSyncDispatch {
+    type Extraction = BlockSyncDispatch;
+    fn extract(msg: network_envelope::Message) -> Extract<Self::Extraction> {
+        match msg {
+            network_envelope::Message::GetBlocksResponse(response) => {
+                Extract::Succeeded(BlockSyncDispatch::Response(response))
+            }
+            network_envelope::Message::NewBlockBroadcastMessage(msg) => {
+                Extract::Succeeded(BlockSyncDispatch::NewBlock(msg))
+            }
+            _ => Extract::Failed(msg),
+        }
+    }
+}
@@ -1,15 +1,31 @@
 use crate::{
     bisq::{
-        payload::{offer_payload, storage_payload, ProtectedStorageEntry, RefreshOfferMessage},
-        SequencedMessageHash,
+        constants::{
+            alert_pub_keys, filter_pub_keys, private_notification_pub_keys, BaseCurrencyNetwork,
+        },
+        payload::{
+            base_block, base_tx, offer_payload, persistable_network_payload, proposal,
+            storage_payload, BaseBlock, BaseTx, BlindVote, OfferPayload, PersistableNetworkPayload,
+            PrivateNotificationMessage, Proposal, ProtectedStorageEntry, RefreshOfferMessage,
+        },
+        PersistentMessageHash, SequencedMessageHash,
     },
     domain::{
+        account_age::AccountAge,
+        alert::{ActiveAlert, PrivateNotification},
         amount::NumberWithPrecision,
         currency::Currency,
+        dao::{
+            BsqBlock, BsqTxInput, DaoBlindVote, DaoProposal, ProposalKind, RawBsqTx,
+            RawBsqTxOutput, BSQ_PRECISION,
+        },
+        dispute_agent::{DisputeAgent, DisputeAgentRole},
+        filter::ActiveFilter,
         market::Market,
         offer::{message::*, *},
+        signed_witness::WitnessSignature,
     },
-    prelude::{sha256, Hash},
+    prelude::{ripemd160, sha256, FromHex, Hash},
 };
 use std::{
     convert::TryFrom,
@@ -73,6 +89,7 @@ pub fn open_offer(entry: ProtectedStorageEntry, hash: SequencedMessageHash) -> O
             ))
         };
         let market = Market::from_currency_pair(base, counter)?;
+        let witness_hash = account_age_witness_hash(&payload);
         Some(OpenOffer::new(
             hash,
             market,
@@ -91,6 +108,8 @@ pub fn open_offer(entry: ProtectedStorageEntry, hash: SequencedMessageHash) -> O
             },
             payload.payment_method_id,
             payload.offer_fee_payment_tx_id,
+            payload.owner_node_address,
+            witness_hash,
             created_at,
             entry.sequence_number.into(),
         ))
@@ -99,6 +118,156 @@ pub fn open_offer(entry: ProtectedStorageEntry, hash: SequencedMessageHash) -> O
     }
 }
 
+const ACCOUNT_AGE_WITNESS_HASH_KEY: &str = "accountAgeWitnessHash";
+fn account_age_witness_hash(payload: &OfferPayload) -> Option<PersistentMessageHash> {
+    let entry = payload
+        .extra_data
+        .iter()
+        .find(|entry| entry.key() == ACCOUNT_AGE_WITNESS_HASH_KEY)?;
+    ripemd160::Hash::from_hex(entry.value())
+        .map(PersistentMessageHash::new)
+        .ok()
+}
+
+pub fn account_age(payload: &PersistableNetworkPayload) -> Option<AccountAge> {
+    if let persistable_network_payload::Message::AccountAgeWitness(witness) =
+        payload.message.as_ref()?
+    {
+        Some(AccountAge {
+            witness_hash: payload.bisq_hash(),
+            witness_date: SystemTime::UNIX_EPOCH + Duration::from_millis(witness.date as u64),
+        })
+    } else {
+        None
+    }
+}
+
+pub fn witness_signature(payload: &PersistableNetworkPayload) -> Option<WitnessSignature> {
+    if let persistable_network_payload::Message::SignedWitness(witness) =
+        payload.message.as_ref()?
+    {
+        if !witness.verify_signature() {
+            warn!(
+                "Ignoring signed witness {:?} with invalid signature",
+                payload.bisq_hash()
+            );
+            return None;
+        }
+        Some(WitnessSignature {
+            account_age_witness_hash: PersistentMessageHash::new(
+                ripemd160::Hash::from_slice(&witness.account_age_witness_hash).ok()?,
+            ),
+            signer_pub_key: witness.signer_pub_key.clone(),
+            owner_pub_key: witness.witness_owner_pub_key.clone(),
+            date: SystemTime::UNIX_EPOCH + Duration::from_millis(witness.date as u64),
+            by_arbitrator: witness.is_signed_by_arbitrator(),
+        })
+    } else {
+        None
+    }
+}
+
+pub fn dao_proposal(proposal: &Proposal, is_temp: bool) -> Option<DaoProposal> {
+    let bsq = |amount: i64| NumberWithPrecision::new(amount as u64, BSQ_PRECISION);
+    let kind = match proposal.message.as_ref()? {
+        proposal::Message::CompensationProposal(p) => ProposalKind::Compensation {
+            requested_bsq: bsq(p.requested_bsq),
+            bsq_address: p.bsq_address.clone(),
+        },
+        proposal::Message::ReimbursementProposal(p) => ProposalKind::Reimbursement {
+            requested_bsq: bsq(p.requested_bsq),
+            bsq_address: p.bsq_address.clone(),
+        },
+        proposal::Message::ChangeParamProposal(p) => ProposalKind::ChangeParam {
+            param: p.param.clone(),
+            param_value: p.param_value.clone(),
+        },
+        proposal::Message::RoleProposal(p) => {
+            let role = p.role.as_ref()?;
+            ProposalKind::BondedRole {
+                role_name: role.name.clone(),
+                bonded_role_type: role.bonded_role_type.clone(),
+            }
+        }
+        proposal::Message::ConfiscateBondProposal(p) => ProposalKind::ConfiscateBond {
+            lockup_tx_id: p.lockup_tx_id.clone(),
+        },
+        proposal::Message::GenericProposal(_) => ProposalKind::Generic,
+        proposal::Message::RemoveAssetProposal(p) => ProposalKind::RemoveAsset {
+            ticker_symbol: p.ticker_symbol.clone(),
+        },
+    };
+    Some(DaoProposal {
+        tx_id: proposal.tx_id.clone(),
+        name: proposal.name.clone(),
+        link: proposal.link.clone(),
+        created_at: SystemTime::UNIX_EPOCH + Duration::from_millis(proposal.creation_date as u64),
+        kind,
+        is_temp,
+    })
+}
+
+pub fn temp_proposal(entry: &ProtectedStorageEntry) -> Option<DaoProposal> {
+    match entry.storage_payload.as_ref()?.message.as_ref()? {
+        storage_payload::Message::TempProposalPayload(payload) => {
+            dao_proposal(payload.proposal.as_ref()?, true)
+        }
+        _ => None,
+    }
+}
+
+pub fn dao_blind_vote(blind_vote: &BlindVote) -> DaoBlindVote {
+    DaoBlindVote {
+        tx_id: blind_vote.tx_id.clone(),
+        stake: NumberWithPrecision::new(blind_vote.stake as u64, BSQ_PRECISION),
+        date: SystemTime::UNIX_EPOCH + Duration::from_millis(blind_vote.date as u64),
+    }
+}
+
+pub fn bsq_block(block: &BaseBlock) -> Option<BsqBlock> {
+    let txs = match block.message.as_ref()? {
+        base_block::Message::RawBlock(raw) => &raw.raw_txs,
+        base_block::Message::Block(parsed) => &parsed.txs,
+    };
+    Some(BsqBlock {
+        height: u32::try_from(block.height).ok()?,
+        txs: txs.iter().map(raw_bsq_tx).collect::<Option<Vec<_>>>()?,
+    })
+}
+
+fn raw_bsq_tx(tx: &BaseTx) -> Option<RawBsqTx> {
+    let outputs = match tx.message.as_ref()? {
+        base_tx::Message::RawTx(raw) => &raw.raw_tx_outputs,
+        base_tx::Message::Tx(parsed) => &parsed.tx_outputs,
+    };
+    Some(RawBsqTx {
+        id: tx.id.clone(),
+        block_height: u32::try_from(tx.block_height).ok()?,
+        time: SystemTime::UNIX_EPOCH + Duration::from_millis(tx.time as u64),
+        inputs: tx
+            .tx_inputs
+            .iter()
+            .map(|input| {
+                Some(BsqTxInput {
+                    tx_id: input.connected_tx_output_tx_id.clone(),
+                    index: u32::try_from(input.connected_tx_output_index).ok()?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?,
+        outputs: outputs
+            .iter()
+            .map(|output| {
+                Some(RawBsqTxOutput {
+                    index: u32::try_from(output.index).ok()?,
+                    value: u64::try_from(output.value).ok()?,
+                    address: Some(output.address.clone()).filter(|address| !address.is_empty()),
+                    op_return_data: output.op_return_data.clone(),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?,
+    })
+}
+
 #[cfg(feature = "statistics")]
 pub use statistics::*;
 #[cfg(feature = "statistics")]
@@ -149,3 +318,125 @@ mod statistics {
         }
     }
 }
+
+pub fn active_filter(
+    entry: &ProtectedStorageEntry,
+    hash: SequencedMessageHash,
+    network: BaseCurrencyNetwork,
+) -> Option<ActiveFilter> {
+    let created_at =
+        SystemTime::UNIX_EPOCH + Duration::from_millis(entry.creation_time_stamp as u64);
+    if let storage_payload::Message::Filter(filter) =
+        entry.storage_payload.as_ref()?.message.as_ref()?
+    {
+        if !filter.verify_signature(filter_pub_keys(network)) {
+            warn!(
+                "Ignoring filter {:?} not signed by a filter developer",
+                hash
+            );
+            return None;
+        }
+        Some(ActiveFilter {
+            bisq_hash: hash,
+            created_at,
+            banned_offer_ids: filter.banned_offer_ids.clone(),
+            banned_node_addresses: filter.banned_node_address.clone(),
+            banned_currencies: filter.banned_currencies.clone(),
+            banned_payment_methods: filter.banned_payment_methods.clone(),
+            disable_dao: filter.disable_dao,
+            disable_trade_below_version: Some(filter.disable_trade_below_version.clone())
+                .filter(|version| !version.is_empty()),
+        })
+    } else {
+        None
+    }
+}
+
+pub fn active_alert(
+    entry: &ProtectedStorageEntry,
+    hash: SequencedMessageHash,
+    network: BaseCurrencyNetwork,
+) -> Option<ActiveAlert> {
+    let created_at =
+        SystemTime::UNIX_EPOCH + Duration::from_millis(entry.creation_time_stamp as u64);
+    if let storage_payload::Message::Alert(alert) =
+        entry.storage_payload.as_ref()?.message.as_ref()?
+    {
+        if !alert.verify_signature(alert_pub_keys(network)) {
+            warn!("Ignoring alert {:?} with invalid signature", hash);
+            return None;
+        }
+        Some(ActiveAlert {
+            bisq_hash: hash,
+            created_at,
+            message: alert.message.clone(),
+            version: alert.version.clone(),
+            is_update_info: alert.is_update_info,
+        })
+    } else {
+        None
+    }
+}
+
+pub fn private_notification(
+    msg: PrivateNotificationMessage,
+    network: BaseCurrencyNetwork,
+) -> Option<PrivateNotification> {
+    let payload = msg.private_notification_payload?;
+    if !payload.verify_signature(private_notification_pub_keys(network)) {
+        warn!("Ignoring private notification with invalid signature");
+        return None;
+    }
+    Some(PrivateNotification {
+        sender: msg.sender_node_address,
+        received_at: SystemTime::now(),
+        message: payload.message,
+    })
+}
+
+pub fn dispute_agent(
+    entry: &ProtectedStorageEntry,
+    hash: SequencedMessageHash,
+) -> Option<DisputeAgent> {
+    let payload = entry.storage_payload.as_ref()?;
+    let (role, node_address, language_codes, registration_date, email_address, info) =
+        match payload.message.as_ref()? {
+            storage_payload::Message::Arbitrator(agent) => (
+                DisputeAgentRole::Arbitrator,
+                &agent.node_address,
+                &agent.language_codes,
+                agent.registration_date,
+                &agent.email_address,
+                &agent.info,
+            ),
+            storage_payload::Message::Mediator(agent) => (
+                DisputeAgentRole::Mediator,
+                &agent.node_address,
+                &agent.language_codes,
+                agent.registration_date,
+                &agent.email_address,
+                &agent.info,
+            ),
+            storage_payload::Message::RefundAgent(agent) => (
+                DisputeAgentRole::RefundAgent,
+                &agent.node_address,
+                &agent.language_codes,
+                agent.registration_date,
+                &agent.email_address,
+                &agent.info,
+            ),
+            _ => return None,
+        };
+    let created_at =
+        SystemTime::UNIX_EPOCH + Duration::from_millis(entry.creation_time_stamp as u64);
+    Some(DisputeAgent {
+        bisq_hash: hash,
+        role,
+        node_address: node_address.clone(),
+        language_codes: language_codes.clone(),
+        registration_date: SystemTime::UNIX_EPOCH + Duration::from_millis(registration_date as u64),
+        email_address: Some(email_address.clone()).filter(|email| !email.is_empty()),
+        info: Some(info.clone()).filter(|info| !info.is_empty()),
+        expires_at: created_at + payload.ttl(),
+    })
+}
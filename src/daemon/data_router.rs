@@ -1,39 +1,89 @@
-use super::convert;
+use super::{
+    convert,
+    store::{DataStore, PayloadStore, Persist, PersistedData, StoreWriter, StoredEntry},
+};
 use crate::{
     bisq::{
+        constants::{
+            BaseCurrencyNetwork, Capability, LOCAL_CAPABILITIES, MAX_PERMITTED_MESSAGE_SIZE,
+        },
         payload::{kind::*, *},
         PersistentMessageHash, SequencedMessageHash,
     },
     domain::{
+        account_age::AccountAgeCache,
+        alert::AlertCache,
+        dao::GovernanceStore,
+        dispute_agent::{AddDisputeAgent, DisputeAgentRegistry, RemoveDisputeAgent},
+        filter::FilterCache,
         offer::{message::*, OfferBook},
+        signed_witness::SignedWitnessCache,
         statistics::{StatsCache, Trade},
         CommandResult,
     },
-    p2p::{dispatch::Receive, message::Broadcast, Broadcaster, ConnectionId},
+    p2p::{
+        dispatch::Receive,
+        message::{Broadcast, Direct},
+        Broadcaster, ConnectionId, Status,
+    },
     prelude::*,
 };
+use prost::{encoding::encoded_len_varint, Message as _};
 use std::{
+    cmp,
     collections::{HashMap, HashSet},
-    mem,
-    time::SystemTime,
+    convert::TryFrom,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
+const PERSIST_INTERVAL: Duration = Duration::from_secs(60);
+// Limits bisq applies when answering GetDataRequests
+const MAX_ENTRIES: usize = 10_000;
+const RESPONSE_OVERHEAD: usize = 1024;
+
 pub struct DataRouter {
+    network: BaseCurrencyNetwork,
     offer_book: Addr<OfferBook>,
+    filter_cache: FilterCache,
+    alert_cache: AlertCache,
+    account_ages: AccountAgeCache,
+    signed_witnesses: SignedWitnessCache,
+    governance: GovernanceStore,
+    dispute_agents: Addr<DisputeAgentRegistry>,
     broadcaster: Addr<Broadcaster>,
+    status: Status,
     #[cfg(feature = "statistics")]
     stats_cache: StatsCache,
+    store: DataStore,
+    store_writer: Addr<StoreWriter>,
+    store_dirty: bool,
+    dirty_payload_stores: HashSet<PayloadStore>,
     sequenced_message_info: HashMap<SequencedMessageHash, SequencedMessageInfo>,
-    persistent_message_info: HashSet<PersistentMessageHash>,
+    peer_capabilities: HashMap<ConnectionId, Vec<i32>>,
+    persistent_message_info: HashMap<PersistentMessageHash, PersistableNetworkPayload>,
 }
 impl Actor for DataRouter {
     type Context = Context<Self>;
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.run_interval(PERSIST_INTERVAL, |data_router, _ctx| {
+            for persist in data_router.take_changed_stores() {
+                data_router.store_writer.do_send(persist);
+            }
+        });
+    }
+    // The writer might already be gone when shutting down, so flush right here
+    fn stopping(&mut self, _ctx: &mut Self::Context) -> Running {
+        for persist in self.take_changed_stores() {
+            self.store.persist(persist);
+        }
+        Running::Stop
+    }
 }
 struct SequencedMessageInfo {
     last_delivery: SystemTime,
     sequence: i32,
-    owner_pub_key: Vec<u8>,
-    original_payload: StoragePayload,
+    entry: ProtectedStorageEntry,
+    removed: bool,
 }
 trait ResultHandler: FnOnce(Result<CommandResult, MailboxError>) -> Result<(), ()> {}
 impl<F> ResultHandler for F where F: FnOnce(Result<CommandResult, MailboxError>) -> Result<(), ()> {}
@@ -41,23 +91,110 @@ impl<F> ResultHandler for F where F: FnOnce(Result<CommandResult, MailboxError>)
 impl DataRouter {
     #[allow(unused_variables)]
     pub fn start(
+        network: BaseCurrencyNetwork,
         offer_book: Addr<OfferBook>,
+        filter_cache: FilterCache,
+        alert_cache: AlertCache,
+        account_ages: AccountAgeCache,
+        signed_witnesses: SignedWitnessCache,
+        governance: GovernanceStore,
+        dispute_agents: Addr<DisputeAgentRegistry>,
         broadcaster: Addr<Broadcaster>,
+        status: Status,
         stats_cache: Option<StatsCache>,
+        store: DataStore,
+        persisted: PersistedData,
     ) -> Addr<DataRouter> {
-        DataRouter {
+        let mut data_router = DataRouter {
+            network,
             offer_book,
+            filter_cache,
+            alert_cache,
+            account_ages,
+            signed_witnesses,
+            governance,
+            dispute_agents,
             broadcaster,
+            status,
             #[cfg(feature = "statistics")]
             stats_cache: stats_cache.expect("StatsCache missing"),
+            store_writer: StoreWriter::start(store.clone()),
+            store,
+            store_dirty: false,
+            dirty_payload_stores: HashSet::new(),
             sequenced_message_info: HashMap::new(),
-            persistent_message_info: HashSet::new(),
+            peer_capabilities: HashMap::new(),
+            persistent_message_info: HashMap::new(),
+        };
+        data_router.restore_persisted_data(persisted);
+        data_router.start()
+    }
+    fn restore_persisted_data(&mut self, PersistedData { entries, payloads }: PersistedData) {
+        entries.into_iter().for_each(
+            |StoredEntry {
+                 entry,
+                 sequence,
+                 last_delivery,
+             }| {
+                if let Some(hash) = self.route_protected_storage_entry(
+                    false,
+                    Some(entry),
+                    Self::ignore_command_result(),
+                ) {
+                    if let Some(info) = self.sequenced_message_info.get_mut(&hash) {
+                        info.sequence = cmp::max(info.sequence, sequence);
+                        info.last_delivery = last_delivery;
+                    }
+                }
+            },
+        );
+        self.route_bootstrap_data(Vec::new(), payloads);
+        self.store_dirty = false;
+        self.dirty_payload_stores.clear();
+    }
+    // Hands out the stores that changed since they were last persisted
+    fn take_changed_stores(&mut self) -> Vec<Persist> {
+        let mut changed = Vec::new();
+        if self.store_dirty {
+            self.store_dirty = false;
+            changed.push(Persist::ProtectedStorage(self.protected_storage_entries()));
+        }
+        for store in self.dirty_payload_stores.drain() {
+            let payloads = self
+                .persistent_message_info
+                .values()
+                .filter(|payload| PayloadStore::of(payload) == Some(store))
+                .cloned()
+                .collect();
+            changed.push(Persist::PayloadStore(store, payloads));
         }
-        .start()
+        changed
+    }
+    fn protected_storage_entries(&self) -> Vec<(SequencedMessageHash, StoredEntry)> {
+        self.sequenced_message_info
+            .iter()
+            .filter(|(_, info)| !info.removed && !info.entry.is_expired())
+            .filter(|(_, info)| match (&info.entry).into() {
+                // Mailbox messages are not meant for us, no need to keep them around
+                StoragePayloadKind::MailboxStoragePayload => false,
+                _ => true,
+            })
+            .map(|(hash, info)| {
+                (
+                    *hash,
+                    StoredEntry {
+                        entry: info.entry.clone(),
+                        sequence: info.sequence,
+                        last_delivery: info.last_delivery,
+                    },
+                )
+            })
+            .collect()
     }
     fn ignore_command_result() -> impl ResultHandler {
         |_result| Ok(())
     }
+    // Whatever the domain accepted gets relayed to the rest of the network
     fn handle_command_result<M>(&self, origin: ConnectionId, original: M) -> impl ResultHandler
     where
         M: Into<network_envelope::Message> + Send + Clone + 'static,
@@ -70,6 +207,85 @@ impl DataRouter {
             Ok(())
         }
     }
+    // For payloads that are handled right here instead of by a domain actor
+    fn complete(result_handler: impl ResultHandler, accepted: bool) {
+        let _ = result_handler(Ok(if accepted {
+            CommandResult::Accepted
+        } else {
+            CommandResult::Ignored
+        }));
+    }
+
+    fn get_data_response(
+        &self,
+        request_nonce: i32,
+        is_get_updated_data_response: bool,
+        excluded_keys: Vec<Vec<u8>>,
+        capabilities: &[i32],
+    ) -> GetDataResponse {
+        let excluded_keys: HashSet<Vec<u8>> = excluded_keys.into_iter().collect();
+        let capabilities: HashSet<Capability> = capabilities
+            .iter()
+            .filter_map(|c| Capability::try_from(*c).ok())
+            .collect();
+        let supported = |required: Option<Capability>| match required {
+            Some(capability) => capabilities.contains(&capability),
+            None => true,
+        };
+        // Leave room for the nonce, the capabilities and the envelope around the items
+        let mut remaining_size = MAX_PERMITTED_MESSAGE_SIZE - RESPONSE_OVERHEAD;
+        let mut fits = |len: usize| {
+            // Each repeated item also costs its tag and length prefix
+            let len = 1 + encoded_len_varint(len as u64) + len;
+            if len > remaining_size {
+                return false;
+            }
+            remaining_size -= len;
+            true
+        };
+
+        let data_set: Vec<StorageEntryWrapper> = self
+            .sequenced_message_info
+            .iter()
+            .filter(|(_, info)| !info.removed && !info.entry.is_expired())
+            .filter(|(_, info)| match (&info.entry).into() {
+                // We don't keep the receiver of mailbox messages around
+                StoragePayloadKind::MailboxStoragePayload => false,
+                kind => supported(kind.required_capability()),
+            })
+            .filter(|(hash, _)| !excluded_keys.contains(&Vec::<u8>::from(**hash)))
+            .map(|(_, info)| StorageEntryWrapper {
+                message: Some(storage_entry_wrapper::Message::ProtectedStorageEntry(
+                    info.entry.clone(),
+                )),
+            })
+            .take(MAX_ENTRIES)
+            .take_while(|wrapper| fits(wrapper.encoded_len()))
+            .collect();
+        let persistable_network_payload_items: Vec<PersistableNetworkPayload> = self
+            .persistent_message_info
+            .iter()
+            .filter(|(_, payload)| {
+                supported(PersistableNetworkPayloadKind::from(*payload).required_capability())
+            })
+            .filter(|(hash, _)| !excluded_keys.contains(&Vec::<u8>::from(**hash)))
+            .map(|(_, payload)| payload.clone())
+            .take(MAX_ENTRIES)
+            .take_while(|payload| fits(payload.encoded_len()))
+            .collect();
+        debug!(
+            "Responding to GetDataRequest with {} entries and {} payloads",
+            data_set.len(),
+            persistable_network_payload_items.len()
+        );
+        GetDataResponse {
+            request_nonce,
+            is_get_updated_data_response,
+            data_set,
+            supported_capabilities: LOCAL_CAPABILITIES.clone(),
+            persistable_network_payload_items,
+        }
+    }
 
     fn route_bootstrap_data(
         &mut self,
@@ -97,63 +313,86 @@ impl DataRouter {
     fn should_deliver_sequenced(
         &mut self,
         hash: SequencedMessageHash,
-        sequence: i32,
-        owner_pub_key: Vec<u8>,
-        original_payload: &StoragePayload,
+        entry: &ProtectedStorageEntry,
+        removed: bool,
     ) -> bool {
-        match self.sequenced_message_info.get_mut(&hash) {
-            Some(ref mut info) if sequence > info.sequence => {
-                info.sequence = sequence;
+        let deliver = match self.sequenced_message_info.get_mut(&hash) {
+            Some(ref mut info) if entry.sequence_number > info.sequence => {
+                info.sequence = entry.sequence_number;
                 info.last_delivery = SystemTime::now();
+                info.removed = removed;
+                if !removed {
+                    info.entry = entry.clone();
+                }
                 true
             }
             None => {
                 self.sequenced_message_info.insert(
                     hash,
                     SequencedMessageInfo {
-                        sequence,
+                        sequence: entry.sequence_number,
                         last_delivery: SystemTime::now(),
-                        owner_pub_key,
-                        original_payload: original_payload.clone(),
+                        entry: entry.clone(),
+                        removed,
                     },
                 );
                 true
             }
             _ => false,
+        };
+        self.store_dirty |= deliver;
+        deliver
+    }
+    fn should_refresh_sequenced(&mut self, msg: &RefreshOfferMessage) -> bool {
+        let info = match self.sequenced_message_info.get_mut(&msg.payload_hash()) {
+            Some(info) if !info.removed && info.sequence < msg.sequence_number => info,
+            _ => return false,
+        };
+        let verified = info
+            .entry
+            .storage_payload
+            .as_ref()
+            .and_then(|payload| msg.verify(&info.entry.owner_pub_key_bytes, payload))
+            .is_some();
+        if verified {
+            let now = SystemTime::now();
+            info.sequence = msg.sequence_number;
+            info.last_delivery = now;
+            // Mirrors bisq's refreshTTL which resets the creation time stamp
+            info.entry.creation_time_stamp = now
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_millis() as i64;
+            self.store_dirty = true;
         }
+        verified
     }
     fn route_storage_entry_wrapper(
         &mut self,
         entry_wrapper: Option<StorageEntryWrapper>,
         result_handler: impl ResultHandler + 'static,
-    ) -> Option<()> {
+    ) -> Option<SequencedMessageHash> {
         match entry_wrapper?.message? {
             storage_entry_wrapper::Message::ProtectedStorageEntry(entry) => {
-                self.route_protected_storage_entry(false, Some(entry), result_handler);
+                self.route_protected_storage_entry(false, Some(entry), result_handler)
             }
             storage_entry_wrapper::Message::ProtectedMailboxStorageEntry(entry) => {
-                self.route_protected_storage_entry(false, entry.entry, result_handler);
+                self.route_protected_storage_entry(false, entry.entry, result_handler)
             }
         }
-        Some(())
     }
     fn route_protected_storage_entry(
         &mut self,
         remove_data: bool,
         entry: Option<ProtectedStorageEntry>,
         result_handler: impl ResultHandler + 'static,
-    ) -> Option<()> {
+    ) -> Option<SequencedMessageHash> {
         let mut entry = entry?;
         let bisq_hash = entry.verify()?;
-        if !self.should_deliver_sequenced(
-            bisq_hash,
-            entry.sequence_number,
-            mem::replace(&mut entry.owner_pub_key_bytes, Vec::new()),
-            entry.storage_payload.as_ref()?,
-        ) {
+        entry.adjust_creation_time_stamp();
+        if !self.should_deliver_sequenced(bisq_hash, &entry, remove_data) {
             return None;
         }
-        #[allow(clippy::single_match)]
         match (&entry).into() {
             StoragePayloadKind::OfferPayload => {
                 convert::open_offer(entry, bisq_hash)
@@ -175,9 +414,57 @@ impl DataRouter {
                         None
                     });
             }
-            _ => (),
+            StoragePayloadKind::Filter => {
+                let updated = if remove_data {
+                    self.filter_cache.remove(bisq_hash)
+                } else {
+                    convert::active_filter(&entry, bisq_hash, self.network)
+                        .map(|filter| self.filter_cache.update(filter))
+                        .unwrap_or(false)
+                };
+                if updated {
+                    info!("Active filter changed {:?}", bisq_hash);
+                    arbiter_spawn!(self.offer_book.send(FilterUpdated))
+                }
+                Self::complete(result_handler, updated);
+            }
+            StoragePayloadKind::Alert => {
+                let updated = if remove_data {
+                    self.alert_cache.remove_alert(bisq_hash)
+                } else {
+                    convert::active_alert(&entry, bisq_hash, self.network)
+                        .map(|alert| self.alert_cache.update_alert(alert))
+                        .unwrap_or(false)
+                };
+                Self::complete(result_handler, updated);
+            }
+            StoragePayloadKind::TempProposalPayload => {
+                if remove_data {
+                    self.governance.remove_temp_proposal(bisq_hash);
+                    Self::complete(result_handler, true);
+                } else if let Some(proposal) = convert::temp_proposal(&entry) {
+                    self.governance.add_temp_proposal(bisq_hash, proposal);
+                    Self::complete(result_handler, true);
+                }
+            }
+            StoragePayloadKind::Arbitrator
+            | StoragePayloadKind::Mediator
+            | StoragePayloadKind::RefundAgent => {
+                if remove_data {
+                    arbiter_spawn!(self
+                        .dispute_agents
+                        .send(RemoveDisputeAgent(bisq_hash))
+                        .then(result_handler))
+                } else if let Some(agent) = convert::dispute_agent(&entry, bisq_hash) {
+                    arbiter_spawn!(self
+                        .dispute_agents
+                        .send(AddDisputeAgent(agent))
+                        .then(result_handler))
+                }
+            }
+            _ => Self::complete(result_handler, true),
         }
-        Some(())
+        Some(bisq_hash)
     }
     #[allow(unused_variables)]
     fn route_persistable_network_payload(
@@ -188,12 +475,53 @@ impl DataRouter {
     ) -> Option<()> {
         let payload = payload?;
         let bisq_hash = payload.bisq_hash();
-        if !self.persistent_message_info.insert(bisq_hash) {
+        if self.persistent_message_info.contains_key(&bisq_hash) {
             return None;
         }
+        self.persistent_message_info
+            .insert(bisq_hash, payload.clone());
+        if let Some(store) = PayloadStore::of(&payload) {
+            self.dirty_payload_stores.insert(store);
+        }
 
-        #[allow(clippy::single_match)]
         match PersistableNetworkPayloadKind::from(&payload) {
+            PersistableNetworkPayloadKind::AccountAgeWitness => {
+                if let Some(account_age) = convert::account_age(&payload) {
+                    self.account_ages.add(account_age);
+                    Self::complete(result_handler, true);
+                }
+            }
+            PersistableNetworkPayloadKind::ProposalPayload => {
+                if let Some(persistable_network_payload::Message::ProposalPayload(payload)) =
+                    payload.message.as_ref()
+                {
+                    if let Some(proposal) = payload
+                        .proposal
+                        .as_ref()
+                        .and_then(|proposal| convert::dao_proposal(proposal, false))
+                    {
+                        self.governance.add_proposal(proposal);
+                        Self::complete(result_handler, true);
+                    }
+                }
+            }
+            PersistableNetworkPayloadKind::BlindVotePayload => {
+                if let Some(persistable_network_payload::Message::BlindVotePayload(payload)) =
+                    payload.message.as_ref()
+                {
+                    if let Some(blind_vote) = payload.blind_vote.as_ref() {
+                        self.governance
+                            .add_blind_vote(convert::dao_blind_vote(blind_vote));
+                        Self::complete(result_handler, true);
+                    }
+                }
+            }
+            PersistableNetworkPayloadKind::SignedWitness => {
+                if let Some(signature) = convert::witness_signature(&payload) {
+                    self.signed_witnesses.add(signature);
+                    Self::complete(result_handler, true);
+                }
+            }
             #[cfg(feature = "statistics")]
             PersistableNetworkPayloadKind::TradeStatistics2 => {
                 if let Some(trade) = convert::trade_statistics2(payload) {
@@ -204,7 +532,7 @@ impl DataRouter {
                     }
                 }
             }
-            _ => (),
+            _ => Self::complete(result_handler, true),
         }
         Some(())
     }
@@ -230,47 +558,117 @@ impl Handler<Receive<DataRouterDispatch>> for DataRouter {
                 self.route_bootstrap_data(data, persistable_network_payloads)
             }
             DataRouterDispatch::RefreshOffer(msg) => {
-                let hash = msg.payload_hash();
-                if let Some(ref mut info) = self.sequenced_message_info.get_mut(&hash) {
-                    if info.sequence < msg.sequence_number
-                        && msg
-                            .verify(&*info.owner_pub_key, &info.original_payload)
-                            .is_some()
-                    {
-                        info.sequence = msg.sequence_number;
-                        info.last_delivery = SystemTime::now();
-                        Arbiter::spawn(
-                            self.offer_book
-                                .send(convert::refresh_offer(&msg))
-                                .then(self.handle_command_result(origin, msg)),
-                        );
-                    }
+                if self.should_refresh_sequenced(&msg) {
+                    Arbiter::spawn(
+                        self.offer_book
+                            .send(convert::refresh_offer(&msg))
+                            .then(self.handle_command_result(origin, msg)),
+                    );
                 }
             }
             DataRouterDispatch::AddData(data) => {
-                self.route_storage_entry_wrapper(
-                    data.entry.clone(),
-                    self.handle_command_result(origin, data),
-                );
+                let entry = data.entry.clone();
+                let result_handler = self.handle_command_result(origin, data);
+                self.route_storage_entry_wrapper(entry, result_handler);
             }
             DataRouterDispatch::RemoveData(data) => {
-                self.route_protected_storage_entry(
-                    true,
-                    data.protected_storage_entry.clone(),
-                    self.handle_command_result(origin, data),
-                );
+                let entry = data.protected_storage_entry.clone();
+                let result_handler = self.handle_command_result(origin, data);
+                self.route_protected_storage_entry(true, entry, result_handler);
             }
             DataRouterDispatch::AddPersistableNetworkPayload(msg) => {
-                self.route_persistable_network_payload(
-                    msg.payload.as_ref().map(Clone::clone),
-                    None,
-                    self.handle_command_result(origin, msg),
-                );
+                let payload = msg.payload.clone();
+                let result_handler = self.handle_command_result(origin, msg);
+                self.route_persistable_network_payload(payload, None, result_handler);
             }
         }
     }
 }
 
+// Bisq sends these to individual nodes, they are not stored or relayed.
+// Bisq seals them as encrypted mailbox messages for the recipient's key ring,
+// which risq doesn't have, so only unsealed messages ever arrive here.
+impl Handler<Receive<PrivateNotificationMessage>> for DataRouter {
+    type Result = ();
+    fn handle(
+        &mut self,
+        Receive(_, msg): Receive<PrivateNotificationMessage>,
+        _ctx: &mut Self::Context,
+    ) {
+        if let Some(notification) = convert::private_notification(msg, self.network) {
+            self.alert_cache.set_private_notification(notification);
+        }
+    }
+}
+
+pub enum GetDataRequest {
+    Preliminary(PreliminaryGetDataRequest),
+    Updated(GetUpdatedDataRequest),
+}
+
+impl Handler<Receive<GetDataRequest>> for DataRouter {
+    type Result = ();
+    fn handle(
+        &mut self,
+        Receive(origin, request): Receive<GetDataRequest>,
+        _ctx: &mut Self::Context,
+    ) {
+        // Peers might disconnect between their preliminary and their updated request
+        let connections = self.status.connections();
+        self.peer_capabilities
+            .retain(|conn, _| connections.contains_key(conn));
+        drop(connections);
+        let response = match request {
+            GetDataRequest::Preliminary(PreliminaryGetDataRequest {
+                nonce,
+                excluded_keys,
+                supported_capabilities,
+            }) => {
+                let response =
+                    self.get_data_response(nonce, false, excluded_keys, &supported_capabilities);
+                self.peer_capabilities
+                    .insert(origin, supported_capabilities);
+                response
+            }
+            GetDataRequest::Updated(GetUpdatedDataRequest {
+                nonce,
+                excluded_keys,
+                ..
+            }) => {
+                // Without a preliminary request fall back to what the peer told Peers,
+                // or only send what needs no capability at all
+                let capabilities = self
+                    .peer_capabilities
+                    .remove(&origin)
+                    .or_else(|| {
+                        self.status
+                            .connections()
+                            .get(&origin)
+                            .and_then(|status| status.capabilities.clone())
+                    })
+                    .unwrap_or_default();
+                self.get_data_response(nonce, true, excluded_keys, &capabilities)
+            }
+        };
+        arbiter_spawn!(self.broadcaster.send(Direct(response, origin)));
+    }
+}
+
+impl PayloadExtractor for GetDataRequest {
+    type Extraction = GetDataRequest;
+    fn extract(msg: network_envelope::Message) -> Extract<Self::Extraction> {
+        match msg {
+            network_envelope::Message::PreliminaryGetDataRequest(request) => {
+                Extract::Succeeded(GetDataRequest::Preliminary(request))
+            }
+            network_envelope::Message::GetUpdatedDataRequest(request) => {
+                Extract::Succeeded(GetDataRequest::Updated(request))
+            }
+            _ => Extract::Failed(msg),
+        }
+    }
+}
+
 impl PayloadExtractor for DataRouterDispatch {
     type Extraction = DataRouterDispatch;
     fn extract(msg: network_envelope::Message) -> Extract<Self::Extraction> {
@@ -0,0 +1,212 @@
+use crate::{
+    bisq::payload::*,
+    domain::dao::{BsqBlocks, DaoStateHashes, StateHashKind},
+    p2p::{
+        dispatch::Receive, message::Broadcast, BootstrapState, Broadcaster, ConnectionId, Status,
+    },
+    prelude::*,
+};
+use std::{
+    collections::VecDeque,
+    convert::TryFrom,
+    time::{Duration, Instant},
+};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const REQUEST_INTERVAL: Duration = Duration::from_secs(5 * 60);
+// Hashes of the most recent blocks are requested again in case peers resolved a fork
+const REQUESTED_BLOCKS: u32 = 10;
+// Responses to older requests are still accepted for a couple of rounds
+const MAX_PENDING_NONCES: usize = 6;
+// Peers may have seen a few blocks more than the local BSQ chain
+const MAX_BLOCKS_AHEAD: u32 = 2;
+
+pub struct DaoStateMonitor {
+    state_hashes: DaoStateHashes,
+    bsq_blocks: BsqBlocks,
+    broadcaster: Addr<Broadcaster>,
+    status: Status,
+    pending_nonces: VecDeque<i32>,
+    requested_at: Option<Instant>,
+}
+impl Actor for DaoStateMonitor {
+    type Context = Context<Self>;
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.run_interval(CHECK_INTERVAL, |monitor, _ctx| {
+            if monitor
+                .requested_at
+                .map(|requested_at| requested_at.elapsed() > REQUEST_INTERVAL)
+                .unwrap_or(true)
+            {
+                monitor.request_all();
+            }
+        });
+    }
+}
+impl DaoStateMonitor {
+    pub fn start(
+        state_hashes: DaoStateHashes,
+        bsq_blocks: BsqBlocks,
+        broadcaster: Addr<Broadcaster>,
+        status: Status,
+    ) -> Addr<DaoStateMonitor> {
+        DaoStateMonitor {
+            state_hashes,
+            bsq_blocks,
+            broadcaster,
+            status,
+            pending_nonces: VecDeque::new(),
+            requested_at: None,
+        }
+        .start()
+    }
+
+    // Starts as soon as the node is bootstrapped and the BSQ chain height is known
+    fn request_all(&mut self) {
+        match self.status.bootstrap_state() {
+            BootstrapState::Bootstrapped { .. } => (),
+            _ => return,
+        }
+        let height = match self.bsq_blocks.chain_height() {
+            Some(height) => height.saturating_sub(REQUESTED_BLOCKS) as i32,
+            None => return,
+        };
+        self.requested_at = Some(Instant::now());
+        self.request(StateHashKind::Dao, height);
+        self.request(StateHashKind::Proposal, height);
+        self.request(StateHashKind::BlindVote, height);
+    }
+
+    fn request(&mut self, kind: StateHashKind, height: i32) {
+        let nonce = gen_nonce();
+        self.pending_nonces.push_back(nonce);
+        if self.pending_nonces.len() > MAX_PENDING_NONCES {
+            self.pending_nonces.pop_front();
+        }
+        let request: network_envelope::Message = match kind {
+            StateHashKind::Dao => GetDaoStateHashesRequest { height, nonce }.into(),
+            StateHashKind::Proposal => GetProposalStateHashesRequest { height, nonce }.into(),
+            StateHashKind::BlindVote => GetBlindVoteStateHashesRequest { height, nonce }.into(),
+        };
+        debug!("Requesting {:?} state hashes from height {}", kind, height);
+        arbiter_spawn!(self.broadcaster.send(Broadcast(request, None)));
+    }
+
+    fn peer(&self, conn: ConnectionId) -> String {
+        self.status
+            .connections()
+            .get(&conn)
+            .and_then(|status| status.addr.as_ref())
+            .map(NodeAddress::to_string)
+            .unwrap_or_else(|| conn.into())
+    }
+
+    // Heights are unauthenticated, so only those near the local chain are kept
+    fn add(&self, kind: StateHashKind, peer: &str, height: i32, hash: Vec<u8>) {
+        let height = match u32::try_from(height) {
+            Ok(height) => height,
+            Err(_) => return,
+        };
+        match self.bsq_blocks.chain_height() {
+            Some(chain_height) if height <= chain_height + MAX_BLOCKS_AHEAD => (),
+            _ => {
+                debug!(
+                    "Ignoring {:?} state hash at height {} from {}",
+                    kind, height, peer
+                );
+                return;
+            }
+        }
+        if self.state_hashes.add(kind, height, hash, peer.to_owned()) {
+            warn!(
+                "{} reported a conflicting {:?} state hash at height {}",
+                peer, kind, height
+            );
+        }
+    }
+}
+
+pub enum StateHashDispatch {
+    NewDao(NewDaoStateHashMessage),
+    NewProposal(NewProposalStateHashMessage),
+    NewBlindVote(NewBlindVoteStateHashMessage),
+    Dao(GetDaoStateHashesResponse),
+    Proposal(GetProposalStateHashesResponse),
+    BlindVote(GetBlindVoteStateHashesResponse),
+}
+
+impl Handler<Receive<StateHashDispatch>> for DaoStateMonitor {
+    type Result = ();
+    fn handle(
+        &mut self,
+        Receive(origin, msg): Receive<StateHashDispatch>,
+        _ctx: &mut Self::Context,
+    ) {
+        let peer = self.peer(origin);
+        match msg {
+            StateHashDispatch::NewDao(msg) => {
+                if let Some(DaoStateHash { height, hash, .. }) = msg.state_hash {
+                    self.add(StateHashKind::Dao, &peer, height, hash);
+                }
+            }
+            StateHashDispatch::NewProposal(msg) => {
+                if let Some(ProposalStateHash { height, hash, .. }) = msg.state_hash {
+                    self.add(StateHashKind::Proposal, &peer, height, hash);
+                }
+            }
+            StateHashDispatch::NewBlindVote(msg) => {
+                if let Some(BlindVoteStateHash { height, hash, .. }) = msg.state_hash {
+                    self.add(StateHashKind::BlindVote, &peer, height, hash);
+                }
+            }
+            StateHashDispatch::Dao(response) => {
+                if self.pending_nonces.contains(&response.request_nonce) {
+                    for DaoStateHash { height, hash, .. } in response.state_hashes {
+                        self.add(StateHashKind::Dao, &peer, height, hash);
+                    }
+                }
+            }
+            StateHashDispatch::Proposal(response) => {
+                if self.pending_nonces.contains(&response.request_nonce) {
+                    for ProposalStateHash { height, hash, .. } in response.state_hashes {
+                        self.add(StateHashKind::Proposal, &peer, height, hash);
+                    }
+                }
+            }
+            StateHashDispatch::BlindVote(response) => {
+                if self.pending_nonces.contains(&response.request_nonce) {
+                    for BlindVoteStateHash { height, hash, .. } in response.state_hashes {
+                        self.add(StateHashKind::BlindVote, &peer, height, hash);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl PayloadExtractor for StateHashDispatch {
+    type Extraction = StateHashDispatch;
+    fn extract(msg: network_envelope::Message) -> Extract<Self::Extraction> {
+        match msg {
+            network_envelope::Message::NewDaoStateHashMessage(msg) => {
+                Extract::Succeeded(StateHashDispatch::NewDao(msg))
+            }
+            network_envelope::Message::NewProposalStateHashMessage(msg) => {
+                Extract::Succeeded(StateHashDispatch::NewProposal(msg))
+            }
+            network_envelope::Message::NewBlindVoteStateHashMessage(msg) => {
+                Extract::Succeeded(StateHashDispatch::NewBlindVote(msg))
+            }
+            network_envelope::Message::GetDaoStateHashesResponse(response) => {
+                Extract::Succeeded(StateHashDispatch::Dao(response))
+            }
+            network_envelope::Message::GetProposalStateHashesResponse(response) => {
+                Extract::Succeeded(StateHashDispatch::Proposal(response))
+            }
+            network_envelope::Message::GetBlindVoteStateHashesResponse(response) => {
+                Extract::Succeeded(StateHashDispatch::BlindVote(response))
+            }
+            _ => Extract::Failed(msg),
+        }
+    }
+}
@@ -0,0 +1,396 @@
+use crate::{
+    bisq::{
+        payload::{kind::PersistableNetworkPayloadKind, persistable_envelope::Message, *},
+        persistence, SequencedMessageHash,
+    },
+    prelude::{Actor, Addr, Handler, SyncArbiter, SyncContext, ToHex},
+};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const PROTECTED_STORAGE_FILE: &str = "PersistedEntryMap";
+const SEQUENCE_NUMBER_FILE: &str = "SequenceNumberMap";
+const TRADE_STATISTICS2_FILE: &str = "TradeStatistics2Store";
+const ACCOUNT_AGE_WITNESS_FILE: &str = "AccountAgeWitnessStore";
+const SIGNED_WITNESS_FILE: &str = "SignedWitnessStore";
+const BSQ_BLOCKS_FILE: &str = "BsqBlocks";
+
+// The stores bisq keeps its persistable network payloads in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PayloadStore {
+    TradeStatistics2,
+    AccountAgeWitness,
+    SignedWitness,
+}
+impl PayloadStore {
+    pub const ALL: [PayloadStore; 3] = [
+        PayloadStore::TradeStatistics2,
+        PayloadStore::AccountAgeWitness,
+        PayloadStore::SignedWitness,
+    ];
+
+    pub fn of(payload: &PersistableNetworkPayload) -> Option<PayloadStore> {
+        match PersistableNetworkPayloadKind::from(payload) {
+            PersistableNetworkPayloadKind::TradeStatistics2 => Some(PayloadStore::TradeStatistics2),
+            PersistableNetworkPayloadKind::AccountAgeWitness => {
+                Some(PayloadStore::AccountAgeWitness)
+            }
+            PersistableNetworkPayloadKind::SignedWitness => Some(PayloadStore::SignedWitness),
+            _ => None,
+        }
+    }
+
+    fn file_name(self) -> &'static str {
+        match self {
+            PayloadStore::TradeStatistics2 => TRADE_STATISTICS2_FILE,
+            PayloadStore::AccountAgeWitness => ACCOUNT_AGE_WITNESS_FILE,
+            PayloadStore::SignedWitness => SIGNED_WITNESS_FILE,
+        }
+    }
+
+    // Payloads that don't belong into this store are dropped
+    fn envelope(self, payloads: Vec<PersistableNetworkPayload>) -> Message {
+        use persistable_network_payload::Message as Payload;
+        let messages = payloads.into_iter().filter_map(|payload| payload.message);
+        match self {
+            PayloadStore::TradeStatistics2 => {
+                Message::TradeStatistics2Store(TradeStatistics2Store {
+                    items: messages
+                        .filter_map(|message| match message {
+                            Payload::TradeStatistics2(stats) => Some(stats),
+                            _ => None,
+                        })
+                        .collect(),
+                })
+            }
+            PayloadStore::AccountAgeWitness => {
+                Message::AccountAgeWitnessStore(AccountAgeWitnessStore {
+                    items: messages
+                        .filter_map(|message| match message {
+                            Payload::AccountAgeWitness(witness) => Some(witness),
+                            _ => None,
+                        })
+                        .collect(),
+                })
+            }
+            PayloadStore::SignedWitness => Message::SignedWitnessStore(SignedWitnessStore {
+                items: messages
+                    .filter_map(|message| match message {
+                        Payload::SignedWitness(witness) => Some(witness),
+                        _ => None,
+                    })
+                    .collect(),
+            }),
+        }
+    }
+}
+
+pub struct StoredEntry {
+    pub entry: ProtectedStorageEntry,
+    pub sequence: i32,
+    pub last_delivery: SystemTime,
+}
+
+#[derive(Default)]
+pub struct PersistedData {
+    pub entries: Vec<StoredEntry>,
+    pub payloads: Vec<PersistableNetworkPayload>,
+}
+impl PersistedData {
+    pub fn excluded_keys(&self) -> Vec<Vec<u8>> {
+        self.entries
+            .iter()
+            .filter_map(|stored| stored.entry.storage_payload.as_ref())
+            .map(|payload| payload.bisq_hash().into())
+            .chain(
+                self.payloads
+                    .iter()
+                    .map(PersistableNetworkPayload::bisq_hash)
+                    .map(Vec::<u8>::from),
+            )
+            .collect()
+    }
+}
+
+// Changed stores handed to the StoreWriter
+pub enum Persist {
+    ProtectedStorage(Vec<(SequencedMessageHash, StoredEntry)>),
+    PayloadStore(PayloadStore, Vec<PersistableNetworkPayload>),
+    BsqBlocks(Vec<BaseBlock>),
+}
+impl actix::Message for Persist {
+    type Result = ();
+}
+
+// Encodes and writes the stores on a thread of its own
+pub struct StoreWriter(DataStore);
+impl Actor for StoreWriter {
+    type Context = SyncContext<Self>;
+}
+impl StoreWriter {
+    pub fn start(store: DataStore) -> Addr<StoreWriter> {
+        SyncArbiter::start(1, move || StoreWriter(store.clone()))
+    }
+}
+impl Handler<Persist> for StoreWriter {
+    type Result = ();
+    fn handle(&mut self, persist: Persist, _ctx: &mut Self::Context) {
+        self.0.persist(persist)
+    }
+}
+
+#[derive(Clone)]
+pub struct DataStore {
+    dir: PathBuf,
+}
+impl DataStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn load(&self) -> PersistedData {
+        PersistedData {
+            entries: self.load_protected_storage(),
+            payloads: PayloadStore::ALL
+                .iter()
+                .flat_map(|store| self.load_payload_store(*store))
+                .collect(),
+        }
+    }
+
+    // Copies the stores of source (ie. a bisq db dir) that are missing here. Stores
+    // risq already keeps itself are left alone, so only the first import has an effect.
+    pub fn import(&self, source: &DataStore) {
+        info!("Importing data stores from {}", source.dir.display());
+        if self.contains(PROTECTED_STORAGE_FILE) {
+            info!("Keeping existing {}", PROTECTED_STORAGE_FILE);
+        } else {
+            let entries: Vec<(SequencedMessageHash, StoredEntry)> = source
+                .load_protected_storage()
+                .into_iter()
+                .filter_map(|stored| {
+                    let hash = stored.entry.storage_payload.as_ref()?.bisq_hash();
+                    Some((hash, stored))
+                })
+                .collect();
+            if !entries.is_empty() {
+                self.persist_protected_storage(entries);
+            }
+        }
+
+        for store in PayloadStore::ALL.iter() {
+            if self.contains(store.file_name()) {
+                info!("Keeping existing {}", store.file_name());
+                continue;
+            }
+            let payloads = source.load_payload_store(*store);
+            if !payloads.is_empty() {
+                self.persist_payload_store(*store, payloads);
+            }
+        }
+    }
+
+    fn contains(&self, file: &str) -> bool {
+        self.dir.join(file).is_file()
+    }
+
+    fn load_protected_storage(&self) -> Vec<StoredEntry> {
+        let sequence_numbers = self.load_sequence_numbers();
+        let entries = match persistence::read(&self.dir.join(PROTECTED_STORAGE_FILE)) {
+            Ok(Some(Message::PersistedEntryMap(map))) => map
+                .persisted_entry_map
+                .into_iter()
+                .filter_map(|(key, entry)| {
+                    if entry.is_expired() {
+                        return None;
+                    }
+                    let (sequence, last_delivery) = match sequence_numbers.get(&key) {
+                        Some(value) if value.sequence_nr >= entry.sequence_number => {
+                            (value.sequence_nr, from_millis(value.time_stamp))
+                        }
+                        _ => (
+                            entry.sequence_number,
+                            from_millis(entry.creation_time_stamp),
+                        ),
+                    };
+                    Some(StoredEntry {
+                        entry,
+                        sequence,
+                        last_delivery,
+                    })
+                })
+                .collect(),
+            Ok(None) => Vec::new(),
+            Ok(Some(_)) => {
+                warn!("Unexpected content in {}", PROTECTED_STORAGE_FILE);
+                Vec::new()
+            }
+            Err(e) => {
+                warn!("Couldn't load {}: {:?}", PROTECTED_STORAGE_FILE, e);
+                Vec::new()
+            }
+        };
+        info!("Loaded {} persisted storage entries", entries.len());
+        entries
+    }
+
+    fn load_payload_store(&self, store: PayloadStore) -> Vec<PersistableNetworkPayload> {
+        use persistable_network_payload::Message as Payload;
+        fn wrap<T>(items: Vec<T>, f: fn(T) -> Payload) -> Vec<PersistableNetworkPayload> {
+            items
+                .into_iter()
+                .map(|item| PersistableNetworkPayload {
+                    message: Some(f(item)),
+                })
+                .collect()
+        }
+        let file = store.file_name();
+        let payloads = match persistence::read(&self.dir.join(file)) {
+            Ok(Some(Message::TradeStatistics2Store(store))) => {
+                wrap(store.items, Payload::TradeStatistics2)
+            }
+            Ok(Some(Message::AccountAgeWitnessStore(store))) => {
+                wrap(store.items, Payload::AccountAgeWitness)
+            }
+            Ok(Some(Message::SignedWitnessStore(store))) => {
+                wrap(store.items, Payload::SignedWitness)
+            }
+            Ok(None) => Vec::new(),
+            Ok(Some(_)) => {
+                warn!("Unexpected content in {}", file);
+                Vec::new()
+            }
+            Err(e) => {
+                warn!("Couldn't load {}: {:?}", file, e);
+                Vec::new()
+            }
+        };
+        info!("Loaded {} payloads from {}", payloads.len(), file);
+        payloads
+    }
+
+    fn load_sequence_numbers(&self) -> HashMap<String, MapValue> {
+        match persistence::read(&self.dir.join(SEQUENCE_NUMBER_FILE)) {
+            Ok(Some(Message::SequenceNumberMap(map))) => map
+                .sequence_number_entries
+                .into_iter()
+                .filter_map(|SequenceNumberEntry { bytes, map_value }| {
+                    Some((bytes?.bytes.to_hex(), map_value?))
+                })
+                .collect(),
+            Ok(_) => HashMap::new(),
+            Err(e) => {
+                warn!("Couldn't load {}: {:?}", SEQUENCE_NUMBER_FILE, e);
+                HashMap::new()
+            }
+        }
+    }
+
+    pub fn persist(&self, persist: Persist) {
+        match persist {
+            Persist::ProtectedStorage(entries) => self.persist_protected_storage(entries),
+            Persist::PayloadStore(store, payloads) => self.persist_payload_store(store, payloads),
+            Persist::BsqBlocks(blocks) => self.persist_bsq_blocks(blocks),
+        }
+    }
+
+    fn persist_protected_storage(&self, entries: Vec<(SequencedMessageHash, StoredEntry)>) {
+        let mut persisted_entry_map = HashMap::with_capacity(entries.len());
+        let mut sequence_number_entries = Vec::with_capacity(entries.len());
+        for (hash, stored) in entries.into_iter() {
+            let bytes: Vec<u8> = hash.into();
+            persisted_entry_map.insert(bytes.to_hex(), stored.entry);
+            sequence_number_entries.push(SequenceNumberEntry {
+                bytes: Some(ByteArray { bytes }),
+                map_value: Some(MapValue {
+                    sequence_nr: stored.sequence,
+                    time_stamp: to_millis(stored.last_delivery),
+                }),
+            });
+        }
+        debug!("Persisting {} storage entries", persisted_entry_map.len());
+        if let Err(e) = persistence::write(
+            &self.dir.join(SEQUENCE_NUMBER_FILE),
+            Message::SequenceNumberMap(SequenceNumberMap {
+                sequence_number_entries,
+            }),
+        )
+        .and_then(|_| {
+            persistence::write(
+                &self.dir.join(PROTECTED_STORAGE_FILE),
+                Message::PersistedEntryMap(PersistedEntryMap {
+                    persisted_entry_map,
+                }),
+            )
+        }) {
+            error!("Couldn't persist storage entries: {:?}", e);
+        }
+    }
+
+    pub fn persist_payload_store(
+        &self,
+        store: PayloadStore,
+        payloads: Vec<PersistableNetworkPayload>,
+    ) {
+        debug!(
+            "Persisting {} payloads to {}",
+            payloads.len(),
+            store.file_name()
+        );
+        if let Err(e) =
+            persistence::write(&self.dir.join(store.file_name()), store.envelope(payloads))
+        {
+            error!("Couldn't persist {}: {:?}", store.file_name(), e);
+        }
+    }
+
+    // The synced blocks are kept in a DaoStateStore that only has its blocks set
+    pub fn load_bsq_blocks(&self) -> Vec<BaseBlock> {
+        let blocks = match persistence::read(&self.dir.join(BSQ_BLOCKS_FILE)) {
+            Ok(Some(Message::DaoStateStore(store))) => store
+                .dao_state
+                .map(|dao_state| dao_state.blocks)
+                .unwrap_or_default(),
+            Ok(None) => Vec::new(),
+            Ok(Some(_)) => {
+                warn!("Unexpected content in {}", BSQ_BLOCKS_FILE);
+                Vec::new()
+            }
+            Err(e) => {
+                warn!("Couldn't load {}: {:?}", BSQ_BLOCKS_FILE, e);
+                Vec::new()
+            }
+        };
+        info!("Loaded {} bsq blocks", blocks.len());
+        blocks
+    }
+
+    fn persist_bsq_blocks(&self, blocks: Vec<BaseBlock>) {
+        debug!("Persisting {} bsq blocks", blocks.len());
+        if let Err(e) = persistence::write(
+            &self.dir.join(BSQ_BLOCKS_FILE),
+            Message::DaoStateStore(DaoStateStore {
+                dao_state: Some(DaoState {
+                    chain_height: blocks.last().map(|block| block.height).unwrap_or_default(),
+                    blocks,
+                    ..Default::default()
+                }),
+                dao_state_hash: Vec::new(),
+            }),
+        ) {
+            error!("Couldn't persist bsq blocks: {:?}", e);
+        }
+    }
+}
+
+fn from_millis(millis: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(millis as u64)
+}
+fn to_millis(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_millis() as i64
+}
@@ -0,0 +1,45 @@
+use crate::bisq::PersistentMessageHash;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct AccountAge {
+    pub witness_hash: PersistentMessageHash,
+    // The date the witness was first published, bisq counts the account age from here
+    pub witness_date: SystemTime,
+}
+impl AccountAge {
+    pub fn age(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(self.witness_date)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct AccountAgeCache {
+    witnesses: Arc<RwLock<HashMap<PersistentMessageHash, SystemTime>>>,
+}
+impl AccountAgeCache {
+    pub fn get(&self, witness_hash: PersistentMessageHash) -> Option<AccountAge> {
+        self.witnesses
+            .read()
+            .expect("Corrupted lock in account age cache")
+            .get(&witness_hash)
+            .map(|witness_date| AccountAge {
+                witness_hash,
+                witness_date: *witness_date,
+            })
+    }
+
+    pub fn add(&self, witness: AccountAge) {
+        self.witnesses
+            .write()
+            .expect("Corrupted lock in account age cache")
+            .entry(witness.witness_hash)
+            .or_insert(witness.witness_date);
+    }
+}
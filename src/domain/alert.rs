@@ -0,0 +1,100 @@
+use crate::bisq::{NodeAddress, SequencedMessageHash};
+use std::{
+    sync::{Arc, RwLock},
+    time::SystemTime,
+};
+
+#[derive(Clone, Debug)]
+pub struct ActiveAlert {
+    pub bisq_hash: SequencedMessageHash,
+    pub created_at: SystemTime,
+    pub message: String,
+    pub version: String,
+    // Update notices announce a new release instead of a general alert
+    pub is_update_info: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct PrivateNotification {
+    pub sender: Option<NodeAddress>,
+    pub received_at: SystemTime,
+    pub message: String,
+}
+
+#[derive(Default)]
+struct Notices {
+    alert: Option<Arc<ActiveAlert>>,
+    private_notification: Option<Arc<PrivateNotification>>,
+}
+
+#[derive(Clone, Default)]
+pub struct AlertCache {
+    notices: Arc<RwLock<Notices>>,
+}
+impl AlertCache {
+    pub fn alert(&self) -> Option<Arc<ActiveAlert>> {
+        self.notices
+            .read()
+            .expect("Corrupted lock in alert cache")
+            .alert
+            .clone()
+    }
+
+    pub fn private_notification(&self) -> Option<Arc<PrivateNotification>> {
+        self.notices
+            .read()
+            .expect("Corrupted lock in alert cache")
+            .private_notification
+            .clone()
+    }
+
+    // Only the newest alert is kept, returns true if it replaced the current one
+    pub fn update_alert(&self, alert: ActiveAlert) -> bool {
+        let mut notices = self.notices.write().expect("Corrupted lock in alert cache");
+        match notices.alert.as_ref() {
+            Some(current) if current.created_at >= alert.created_at => false,
+            _ => {
+                log_notice(
+                    if alert.is_update_info {
+                        format!("BISQ UPDATE {}", alert.version)
+                    } else {
+                        "BISQ ALERT".to_string()
+                    },
+                    &alert.message,
+                );
+                notices.alert = Some(Arc::new(alert));
+                true
+            }
+        }
+    }
+
+    pub fn remove_alert(&self, bisq_hash: SequencedMessageHash) -> bool {
+        let mut notices = self.notices.write().expect("Corrupted lock in alert cache");
+        match notices.alert.as_ref() {
+            Some(current) if current.bisq_hash == bisq_hash => {
+                info!("Bisq alert was removed");
+                notices.alert = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn set_private_notification(&self, notification: PrivateNotification) {
+        log_notice(
+            "BISQ PRIVATE NOTIFICATION".to_string(),
+            &notification.message,
+        );
+        self.notices
+            .write()
+            .expect("Corrupted lock in alert cache")
+            .private_notification = Some(Arc::new(notification));
+    }
+}
+
+// Operators of headless nodes mostly read logs, so make notices stand out
+fn log_notice(title: String, message: &str) {
+    warn!("==================== {} ====================", title);
+    message.lines().for_each(|line| warn!("{}", line));
+    warn!("==================== {} ====================", title);
+}
@@ -0,0 +1,111 @@
+use super::{BsqAddress, BsqState, BsqSupply, BsqTx};
+use std::{
+    sync::{Arc, RwLock},
+    time::SystemTime,
+};
+
+#[derive(Clone, Debug)]
+pub struct BsqTxInput {
+    pub tx_id: String,
+    pub index: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct RawBsqTxOutput {
+    pub index: u32,
+    // In satoshis, only the outputs the BSQ rules consider are colored
+    pub value: u64,
+    pub address: Option<String>,
+    pub op_return_data: Vec<u8>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RawBsqTx {
+    pub id: String,
+    pub block_height: u32,
+    pub time: SystemTime,
+    pub inputs: Vec<BsqTxInput>,
+    pub outputs: Vec<RawBsqTxOutput>,
+}
+
+// Full DAO nodes only include the txs relevant for BSQ. The block sync checks the
+// linking of the blocks, so only their height is kept.
+#[derive(Clone, Debug)]
+pub struct BsqBlock {
+    pub height: u32,
+    pub txs: Vec<RawBsqTx>,
+}
+
+#[derive(Default)]
+struct Chain {
+    blocks: Vec<BsqBlock>,
+    state: BsqState,
+}
+
+#[derive(Clone, Default)]
+pub struct BsqBlocks {
+    chain: Arc<RwLock<Chain>>,
+}
+impl BsqBlocks {
+    pub fn chain_height(&self) -> Option<u32> {
+        self.chain
+            .read()
+            .expect("Corrupted lock in bsq blocks")
+            .blocks
+            .last()
+            .map(|block| block.height)
+    }
+
+    // The first block holds the genesis tx
+    pub fn genesis_height(&self) -> Option<u32> {
+        self.chain
+            .read()
+            .expect("Corrupted lock in bsq blocks")
+            .blocks
+            .first()
+            .map(|block| block.height)
+    }
+
+    pub fn push(&self, block: BsqBlock) {
+        let mut chain = self.chain.write().expect("Corrupted lock in bsq blocks");
+        chain.state.apply_block(&block);
+        chain.blocks.push(block);
+    }
+
+    // Drops all blocks from the given height on, ie. after a reorg.
+    // The state gets rebuilt from the remaining blocks.
+    pub fn truncate(&self, height: u32) {
+        let mut chain = self.chain.write().expect("Corrupted lock in bsq blocks");
+        chain.blocks.retain(|block| block.height < height);
+        let mut state = BsqState::default();
+        chain
+            .blocks
+            .iter()
+            .for_each(|block| state.apply_block(block));
+        chain.state = state;
+    }
+
+    pub fn tx(&self, tx_id: &str) -> Option<BsqTx> {
+        self.chain
+            .read()
+            .expect("Corrupted lock in bsq blocks")
+            .state
+            .tx(tx_id)
+    }
+
+    pub fn address(&self, address: &str) -> Option<BsqAddress> {
+        self.chain
+            .read()
+            .expect("Corrupted lock in bsq blocks")
+            .state
+            .address(address)
+    }
+
+    pub fn supply(&self) -> BsqSupply {
+        self.chain
+            .read()
+            .expect("Corrupted lock in bsq blocks")
+            .state
+            .supply()
+    }
+}
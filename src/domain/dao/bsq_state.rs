@@ -0,0 +1,466 @@
+use super::{BsqBlock, BsqTxInput, RawBsqTx};
+use std::{
+    collections::{HashMap, HashSet},
+    time::SystemTime,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BsqTxType {
+    Genesis,
+    TransferBsq,
+    PayTradeFee,
+    Proposal,
+    CompensationRequest,
+    ReimbursementRequest,
+    BlindVote,
+    VoteReveal,
+    Lockup,
+    Unlock,
+    AssetListingFee,
+    ProofOfBurn,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BsqTxOutputType {
+    GenesisOutput,
+    BsqOutput,
+    BtcOutput,
+    ProposalOpReturnOutput,
+    CompReqOpReturnOutput,
+    ReimbursementOpReturnOutput,
+    IssuanceCandidateOutput,
+    BlindVoteLockStakeOutput,
+    BlindVoteOpReturnOutput,
+    VoteRevealUnlockStakeOutput,
+    VoteRevealOpReturnOutput,
+    AssetListingFeeOpReturnOutput,
+    ProofOfBurnOpReturnOutput,
+    LockupOutput,
+    LockupOpReturnOutput,
+    UnlockOutput,
+}
+impl BsqTxOutputType {
+    // Issuance candidates only become BSQ once their request got accepted
+    pub fn is_bsq(self) -> bool {
+        match self {
+            Self::GenesisOutput
+            | Self::BsqOutput
+            | Self::BlindVoteLockStakeOutput
+            | Self::VoteRevealUnlockStakeOutput
+            | Self::LockupOutput
+            | Self::UnlockOutput => true,
+            _ => false,
+        }
+    }
+}
+
+// Same tags bisq puts in the first byte of the OP_RETURN data
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OpReturnType {
+    Proposal,
+    CompensationRequest,
+    ReimbursementRequest,
+    BlindVote,
+    VoteReveal,
+    Lockup,
+    AssetListingFee,
+    ProofOfBurn,
+}
+impl OpReturnType {
+    fn from_data(data: &[u8]) -> Option<OpReturnType> {
+        match data.first()? {
+            0x10 => Some(Self::Proposal),
+            0x11 => Some(Self::CompensationRequest),
+            0x12 => Some(Self::ReimbursementRequest),
+            0x13 => Some(Self::BlindVote),
+            0x14 => Some(Self::VoteReveal),
+            0x15 => Some(Self::Lockup),
+            0x16 => Some(Self::AssetListingFee),
+            0x17 => Some(Self::ProofOfBurn),
+            _ => None,
+        }
+    }
+
+    fn output_type(self) -> BsqTxOutputType {
+        match self {
+            Self::Proposal => BsqTxOutputType::ProposalOpReturnOutput,
+            Self::CompensationRequest => BsqTxOutputType::CompReqOpReturnOutput,
+            Self::ReimbursementRequest => BsqTxOutputType::ReimbursementOpReturnOutput,
+            Self::BlindVote => BsqTxOutputType::BlindVoteOpReturnOutput,
+            Self::VoteReveal => BsqTxOutputType::VoteRevealOpReturnOutput,
+            Self::Lockup => BsqTxOutputType::LockupOpReturnOutput,
+            Self::AssetListingFee => BsqTxOutputType::AssetListingFeeOpReturnOutput,
+            Self::ProofOfBurn => BsqTxOutputType::ProofOfBurnOpReturnOutput,
+        }
+    }
+
+    fn tx_type(self) -> BsqTxType {
+        match self {
+            Self::Proposal => BsqTxType::Proposal,
+            Self::CompensationRequest => BsqTxType::CompensationRequest,
+            Self::ReimbursementRequest => BsqTxType::ReimbursementRequest,
+            Self::BlindVote => BsqTxType::BlindVote,
+            Self::VoteReveal => BsqTxType::VoteReveal,
+            Self::Lockup => BsqTxType::Lockup,
+            Self::AssetListingFee => BsqTxType::AssetListingFee,
+            Self::ProofOfBurn => BsqTxType::ProofOfBurn,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BsqSpentInfo {
+    pub block_height: u32,
+    pub tx_id: String,
+    pub input_index: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct BsqTxOutput {
+    pub index: u32,
+    pub value: u64,
+    pub address: Option<String>,
+    pub output_type: BsqTxOutputType,
+    pub spent: Option<BsqSpentInfo>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BsqTx {
+    pub id: String,
+    pub block_height: u32,
+    pub time: SystemTime,
+    pub tx_type: BsqTxType,
+    pub inputs: Vec<BsqTxInput>,
+    pub outputs: Vec<BsqTxOutput>,
+    pub bsq_input_value: u64,
+    pub burnt_fee: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct BsqAddress {
+    pub address: String,
+    pub balance: u64,
+    pub unspent_outputs: Vec<BsqTxOutput>,
+    // Txs paying to or spending from the address, latest first
+    pub tx_ids: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BsqSupply {
+    pub chain_height: Option<u32>,
+    pub genesis: u64,
+    pub burnt: u64,
+    pub unspent: u64,
+    pub locked_in_bonds: u64,
+    pub unspent_outputs: usize,
+}
+type OutputKey = (String, u32);
+
+// Replays the BSQ rules of bisq's TxParser over the synced blocks. The first block is
+// expected to hold the genesis tx, as delivered by full nodes syncing from scratch.
+// Vote results can't be derived from the raw blocks, so it's unknown which issuance
+// candidates became BSQ. The supply leaves issuance out.
+#[derive(Default)]
+pub struct BsqState {
+    txs: HashMap<String, BsqTx>,
+    unspent: HashSet<OutputKey>,
+    issuance_candidates: HashSet<OutputKey>,
+    addresses: HashMap<String, Vec<OutputKey>>,
+    address_txs: HashMap<String, Vec<String>>,
+    supply: BsqSupply,
+}
+impl BsqState {
+    pub fn apply_block(&mut self, block: &BsqBlock) {
+        let is_genesis_block = self.supply.chain_height.is_none();
+        self.supply.chain_height = Some(block.height);
+        for tx in block.txs.iter() {
+            if is_genesis_block && self.txs.is_empty() {
+                self.apply_genesis(tx);
+            } else {
+                self.apply_tx(block.height, tx);
+            }
+        }
+    }
+
+    fn apply_genesis(&mut self, tx: &RawBsqTx) {
+        let outputs: Vec<BsqTxOutput> = tx
+            .outputs
+            .iter()
+            .map(|output| BsqTxOutput {
+                index: output.index,
+                value: output.value,
+                address: output.address.clone(),
+                output_type: BsqTxOutputType::GenesisOutput,
+                spent: None,
+            })
+            .collect();
+        self.supply.genesis = outputs.iter().map(|output| output.value).sum();
+        self.add_tx(BsqTx {
+            id: tx.id.clone(),
+            block_height: tx.block_height,
+            time: tx.time,
+            tx_type: BsqTxType::Genesis,
+            inputs: tx.inputs.clone(),
+            outputs,
+            bsq_input_value: 0,
+            burnt_fee: 0,
+        });
+    }
+
+    fn apply_tx(&mut self, height: u32, tx: &RawBsqTx) {
+        let mut bsq_input_value = 0;
+        let mut spends_lockup = false;
+        let mut spends_issuance_candidate = false;
+        let mut spent_addresses = Vec::new();
+        for (input_index, input) in tx.inputs.iter().enumerate() {
+            let key = (input.tx_id.clone(), input.index);
+            if self.issuance_candidates.remove(&key) {
+                spends_issuance_candidate = true;
+                continue;
+            }
+            if !self.unspent.remove(&key) {
+                continue;
+            }
+            let output = match self
+                .txs
+                .get_mut(&input.tx_id)
+                .and_then(|spent_tx| spent_tx.outputs.get_mut(input.index as usize))
+            {
+                Some(output) => output,
+                None => continue,
+            };
+            spends_lockup |= output.output_type == BsqTxOutputType::LockupOutput;
+            bsq_input_value += output.value;
+            output.spent = Some(BsqSpentInfo {
+                block_height: height,
+                tx_id: tx.id.clone(),
+                input_index: input_index as u32,
+            });
+            spent_addresses.extend(output.address.clone());
+        }
+        if bsq_input_value == 0 {
+            return;
+        }
+
+        let op_return = tx
+            .outputs
+            .last()
+            .filter(|output| output.value == 0)
+            .and_then(|output| OpReturnType::from_data(&output.op_return_data));
+        let mut available = bsq_input_value;
+        let mut prohibit_bsq_outputs = false;
+        let outputs: Vec<BsqTxOutput> = tx
+            .outputs
+            .iter()
+            .map(|output| {
+                let output_type = if !output.op_return_data.is_empty() {
+                    op_return
+                        .map(OpReturnType::output_type)
+                        .unwrap_or(BsqTxOutputType::BtcOutput)
+                } else if !prohibit_bsq_outputs && output.value > 0 && available >= output.value {
+                    available -= output.value;
+                    match (output.index, op_return) {
+                        (0, Some(OpReturnType::BlindVote)) => {
+                            BsqTxOutputType::BlindVoteLockStakeOutput
+                        }
+                        (0, Some(OpReturnType::VoteReveal)) => {
+                            BsqTxOutputType::VoteRevealUnlockStakeOutput
+                        }
+                        (0, Some(OpReturnType::Lockup)) => BsqTxOutputType::LockupOutput,
+                        (0, None) if spends_lockup => BsqTxOutputType::UnlockOutput,
+                        _ => BsqTxOutputType::BsqOutput,
+                    }
+                } else {
+                    prohibit_bsq_outputs = true;
+                    match (output.index, op_return) {
+                        (1, Some(OpReturnType::CompensationRequest))
+                        | (1, Some(OpReturnType::ReimbursementRequest)) => {
+                            BsqTxOutputType::IssuanceCandidateOutput
+                        }
+                        _ => BsqTxOutputType::BtcOutput,
+                    }
+                };
+                BsqTxOutput {
+                    index: output.index,
+                    value: output.value,
+                    address: output.address.clone(),
+                    output_type,
+                    spent: None,
+                }
+            })
+            .collect();
+
+        // The candidate might have been BSQ, so what looks left over isn't known to be burnt
+        let burnt_fee = if spends_issuance_candidate {
+            0
+        } else {
+            available
+        };
+        let tx_type = match op_return {
+            Some(op_return) => op_return.tx_type(),
+            None if spends_lockup => BsqTxType::Unlock,
+            None if burnt_fee > 0 => BsqTxType::PayTradeFee,
+            None => BsqTxType::TransferBsq,
+        };
+        self.supply.burnt += burnt_fee;
+        for address in spent_addresses {
+            self.add_address_tx(address, &tx.id);
+        }
+        self.add_tx(BsqTx {
+            id: tx.id.clone(),
+            block_height: tx.block_height,
+            time: tx.time,
+            tx_type,
+            inputs: tx.inputs.clone(),
+            outputs,
+            bsq_input_value,
+            burnt_fee,
+        });
+    }
+
+    fn add_tx(&mut self, tx: BsqTx) {
+        for output in tx.outputs.iter() {
+            let key = (tx.id.clone(), output.index);
+            if output.output_type == BsqTxOutputType::IssuanceCandidateOutput {
+                self.issuance_candidates.insert(key);
+            } else if output.output_type.is_bsq() {
+                self.unspent.insert(key.clone());
+                if let Some(address) = output.address.as_ref() {
+                    self.addresses
+                        .entry(address.clone())
+                        .or_insert_with(Vec::new)
+                        .push(key);
+                    self.add_address_tx(address.clone(), &tx.id);
+                }
+            }
+        }
+        self.txs.insert(tx.id.clone(), tx);
+    }
+
+    fn add_address_tx(&mut self, address: String, tx_id: &str) {
+        let tx_ids = self.address_txs.entry(address).or_insert_with(Vec::new);
+        if tx_ids.last().map(String::as_str) != Some(tx_id) {
+            tx_ids.push(tx_id.to_owned());
+        }
+    }
+
+    fn output(&self, (tx_id, index): &OutputKey) -> Option<&BsqTxOutput> {
+        self.txs.get(tx_id)?.outputs.get(*index as usize)
+    }
+
+    pub fn tx(&self, tx_id: &str) -> Option<BsqTx> {
+        self.txs.get(tx_id).cloned()
+    }
+
+    pub fn address(&self, address: &str) -> Option<BsqAddress> {
+        let keys = self.addresses.get(address)?;
+        let unspent_outputs: Vec<BsqTxOutput> = keys
+            .iter()
+            .filter(|key| self.unspent.contains(*key))
+            .filter_map(|key| self.output(key))
+            .cloned()
+            .collect();
+        Some(BsqAddress {
+            address: address.to_owned(),
+            balance: unspent_outputs.iter().map(|output| output.value).sum(),
+            unspent_outputs,
+            tx_ids: self
+                .address_txs
+                .get(address)
+                .map(|tx_ids| tx_ids.iter().rev().cloned().collect())
+                .unwrap_or_default(),
+        })
+    }
+
+    pub fn supply(&self) -> BsqSupply {
+        let mut supply = self.supply;
+        let unspent = self.unspent.iter().filter_map(|key| self.output(key));
+        for output in unspent {
+            supply.unspent += output.value;
+            supply.unspent_outputs += 1;
+            if output.output_type == BsqTxOutputType::LockupOutput {
+                supply.locked_in_bonds += output.value;
+            }
+        }
+        supply
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dao::RawBsqTxOutput;
+
+    fn tx(id: &str, height: u32, inputs: &[(&str, u32)], outputs: &[(u64, &str)]) -> RawBsqTx {
+        RawBsqTx {
+            id: id.to_owned(),
+            block_height: height,
+            time: SystemTime::UNIX_EPOCH,
+            inputs: inputs
+                .iter()
+                .map(|(tx_id, index)| BsqTxInput {
+                    tx_id: (*tx_id).to_owned(),
+                    index: *index,
+                })
+                .collect(),
+            outputs: outputs
+                .iter()
+                .enumerate()
+                .map(|(index, (value, address))| RawBsqTxOutput {
+                    index: index as u32,
+                    value: *value,
+                    address: Some((*address).to_owned()).filter(|address| !address.is_empty()),
+                    op_return_data: if address.is_empty() {
+                        vec![0x11, 0x01]
+                    } else {
+                        Vec::new()
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    fn block(height: u32, txs: Vec<RawBsqTx>) -> BsqBlock {
+        BsqBlock { height, txs }
+    }
+
+    #[test]
+    fn parse_bsq_txs() {
+        let mut state = BsqState::default();
+        state.apply_block(&block(
+            100,
+            vec![tx("genesis", 100, &[], &[(1000, "a"), (500, "b")])],
+        ));
+        state.apply_block(&block(
+            101,
+            vec![
+                tx("fee", 101, &[("genesis", 0)], &[(900, "c")]),
+                tx(
+                    "comp",
+                    101,
+                    &[("genesis", 1), ("btc", 0)],
+                    &[(400, "b"), (50_000, "b"), (0, "")],
+                ),
+            ],
+        ));
+        assert_eq!(state.tx("fee").unwrap().tx_type, BsqTxType::PayTradeFee);
+        assert_eq!(state.tx("fee").unwrap().burnt_fee, 100);
+        let comp = state.tx("comp").unwrap();
+        assert_eq!(comp.tx_type, BsqTxType::CompensationRequest);
+        assert_eq!(
+            comp.outputs[1].output_type,
+            BsqTxOutputType::IssuanceCandidateOutput
+        );
+
+        let b = state.address("b").unwrap();
+        assert_eq!(b.balance, 400);
+        assert_eq!(b.tx_ids, vec!["comp", "genesis"]);
+
+        let supply = state.supply();
+        assert_eq!(supply.genesis, 1500);
+        assert_eq!(supply.burnt, 200);
+        assert_eq!(supply.unspent, 1300);
+        assert_eq!(supply.unspent_outputs, 2);
+        assert_eq!(supply.chain_height, Some(101));
+    }
+}
@@ -0,0 +1,186 @@
+use super::BsqBlocks;
+use crate::{
+    bisq::{
+        constants::{dao_cycle_blocks, BaseCurrencyNetwork},
+        SequencedMessageHash,
+    },
+    domain::amount::NumberWithPrecision,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, RwLock},
+    time::SystemTime,
+};
+
+#[derive(Clone, Debug)]
+pub enum ProposalKind {
+    Compensation {
+        requested_bsq: NumberWithPrecision,
+        bsq_address: String,
+    },
+    Reimbursement {
+        requested_bsq: NumberWithPrecision,
+        bsq_address: String,
+    },
+    ChangeParam {
+        param: String,
+        param_value: String,
+    },
+    BondedRole {
+        role_name: String,
+        bonded_role_type: String,
+    },
+    ConfiscateBond {
+        lockup_tx_id: String,
+    },
+    Generic,
+    RemoveAsset {
+        ticker_symbol: String,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub struct DaoProposal {
+    pub tx_id: String,
+    pub name: String,
+    pub link: String,
+    pub created_at: SystemTime,
+    pub kind: ProposalKind,
+    // Temp proposals are only kept in the p2p network until the proposal phase ends
+    pub is_temp: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct DaoBlindVote {
+    pub tx_id: String,
+    pub stake: NumberWithPrecision,
+    pub date: SystemTime,
+}
+
+#[derive(Clone, Debug)]
+pub struct DaoCycle {
+    // Counted from the cycle starting at the genesis block, which is cycle 1
+    pub number: u32,
+    pub first_block_height: u32,
+    pub proposals: Vec<DaoProposal>,
+    pub blind_votes: Vec<DaoBlindVote>,
+}
+
+#[derive(Default)]
+struct Governance {
+    proposals: HashMap<String, DaoProposal>,
+    temp_proposals: HashMap<SequencedMessageHash, DaoProposal>,
+    blind_votes: HashMap<String, DaoBlindVote>,
+}
+
+#[derive(Clone)]
+pub struct GovernanceStore {
+    governance: Arc<RwLock<Governance>>,
+    cycle_blocks: u32,
+}
+impl GovernanceStore {
+    pub fn new(network: BaseCurrencyNetwork) -> Self {
+        Self {
+            governance: Arc::new(RwLock::new(Governance::default())),
+            cycle_blocks: dao_cycle_blocks(network),
+        }
+    }
+
+    pub fn add_proposal(&self, proposal: DaoProposal) {
+        self.governance
+            .write()
+            .expect("Corrupted lock in governance store")
+            .proposals
+            .insert(proposal.tx_id.clone(), proposal);
+    }
+
+    pub fn add_temp_proposal(&self, bisq_hash: SequencedMessageHash, proposal: DaoProposal) {
+        self.governance
+            .write()
+            .expect("Corrupted lock in governance store")
+            .temp_proposals
+            .insert(bisq_hash, proposal);
+    }
+
+    pub fn remove_temp_proposal(&self, bisq_hash: SequencedMessageHash) {
+        self.governance
+            .write()
+            .expect("Corrupted lock in governance store")
+            .temp_proposals
+            .remove(&bisq_hash);
+    }
+
+    pub fn add_blind_vote(&self, blind_vote: DaoBlindVote) {
+        self.governance
+            .write()
+            .expect("Corrupted lock in governance store")
+            .blind_votes
+            .insert(blind_vote.tx_id.clone(), blind_vote);
+    }
+
+    // Cycles start at the genesis block like in bisq's CycleService, so proposals and
+    // blind votes show up once their tx is part of the synced BSQ chain. Temp proposals
+    // without a confirmed tx belong to the cycle at the chain tip.
+    pub fn cycles(&self, bsq_blocks: &BsqBlocks) -> Vec<DaoCycle> {
+        let (genesis_height, chain_height) =
+            match (bsq_blocks.genesis_height(), bsq_blocks.chain_height()) {
+                (Some(genesis_height), Some(chain_height)) => (genesis_height, chain_height),
+                _ => return Vec::new(),
+            };
+        let cycle_blocks = self.cycle_blocks;
+        let cycle_index = |height: u32| height.saturating_sub(genesis_height) / cycle_blocks;
+        let tx_cycle_index =
+            |tx_id: &str| bsq_blocks.tx(tx_id).map(|tx| cycle_index(tx.block_height));
+        let governance = self
+            .governance
+            .read()
+            .expect("Corrupted lock in governance store");
+
+        let mut cycles: BTreeMap<u32, DaoCycle> = BTreeMap::new();
+        let new_cycle = |index: u32| DaoCycle {
+            number: index + 1,
+            first_block_height: genesis_height + index * cycle_blocks,
+            proposals: Vec::new(),
+            blind_votes: Vec::new(),
+        };
+        for proposal in governance.proposals.values() {
+            if let Some(index) = tx_cycle_index(&proposal.tx_id) {
+                cycles
+                    .entry(index)
+                    .or_insert_with(|| new_cycle(index))
+                    .proposals
+                    .push(proposal.clone());
+            }
+        }
+        for proposal in governance
+            .temp_proposals
+            .values()
+            .filter(|temp| !governance.proposals.contains_key(&temp.tx_id))
+        {
+            let index =
+                tx_cycle_index(&proposal.tx_id).unwrap_or_else(|| cycle_index(chain_height));
+            cycles
+                .entry(index)
+                .or_insert_with(|| new_cycle(index))
+                .proposals
+                .push(proposal.clone());
+        }
+        for blind_vote in governance.blind_votes.values() {
+            if let Some(index) = tx_cycle_index(&blind_vote.tx_id) {
+                cycles
+                    .entry(index)
+                    .or_insert_with(|| new_cycle(index))
+                    .blind_votes
+                    .push(blind_vote.clone());
+            }
+        }
+        cycles
+            .into_iter()
+            .map(|(_, mut cycle)| {
+                cycle.proposals.sort_by_key(|proposal| proposal.created_at);
+                cycle.blind_votes.sort_by_key(|vote| vote.date);
+                cycle
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,12 @@
+mod blocks;
+mod bsq_state;
+mod governance;
+mod state_hashes;
+
+pub use blocks::*;
+pub use bsq_state::*;
+pub use governance::*;
+pub use state_hashes::*;
+
+// BSQ amounts are counted in satoshis, 1 BSQ = 100 satoshis
+pub const BSQ_PRECISION: u32 = 2;
@@ -0,0 +1,100 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, RwLock},
+};
+
+// Number of block heights kept per kind of state hash
+const MAX_HEIGHTS: usize = 100;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StateHashKind {
+    Dao,
+    Proposal,
+    BlindVote,
+}
+
+#[derive(Clone, Debug)]
+pub struct StateHashVariant {
+    pub hash: Vec<u8>,
+    pub peers: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BlockStateHashes {
+    pub kind: StateHashKind,
+    pub height: u32,
+    // Ordered by the number of peers reporting them
+    pub variants: Vec<StateHashVariant>,
+}
+impl BlockStateHashes {
+    pub fn in_conflict(&self) -> bool {
+        self.variants.len() > 1
+    }
+}
+
+type PeerHashes = HashMap<String, Vec<u8>>;
+
+#[derive(Clone, Default)]
+pub struct DaoStateHashes {
+    hashes: Arc<RwLock<HashMap<StateHashKind, BTreeMap<u32, PeerHashes>>>>,
+}
+impl DaoStateHashes {
+    // Returns true if the hash disagrees with what other peers reported for that height
+    pub fn add(&self, kind: StateHashKind, height: u32, hash: Vec<u8>, peer: String) -> bool {
+        let mut hashes = self
+            .hashes
+            .write()
+            .expect("Corrupted lock in dao state hashes");
+        let heights = hashes.entry(kind).or_insert_with(BTreeMap::new);
+        if heights.len() >= MAX_HEIGHTS && !heights.contains_key(&height) {
+            match heights.keys().next() {
+                Some(lowest) if *lowest > height => return false,
+                Some(lowest) => {
+                    let lowest = *lowest;
+                    heights.remove(&lowest);
+                }
+                None => (),
+            }
+        }
+        let peer_hashes = heights.entry(height).or_insert_with(HashMap::new);
+        let conflict = peer_hashes
+            .iter()
+            .any(|(other, other_hash)| *other != peer && *other_hash != hash);
+        peer_hashes.insert(peer, hash);
+        conflict
+    }
+
+    // Latest heights first
+    pub fn blocks(&self, kind: StateHashKind) -> Vec<BlockStateHashes> {
+        self.hashes
+            .read()
+            .expect("Corrupted lock in dao state hashes")
+            .get(&kind)
+            .map(|heights| {
+                heights
+                    .iter()
+                    .rev()
+                    .map(|(height, peer_hashes)| {
+                        let mut variants: Vec<StateHashVariant> = Vec::new();
+                        for (peer, hash) in peer_hashes.iter() {
+                            match variants.iter_mut().find(|variant| variant.hash == *hash) {
+                                Some(variant) => variant.peers.push(peer.clone()),
+                                None => variants.push(StateHashVariant {
+                                    hash: hash.clone(),
+                                    peers: vec![peer.clone()],
+                                }),
+                            }
+                        }
+                        variants.iter_mut().for_each(|variant| variant.peers.sort());
+                        variants.sort_by(|a, b| b.peers.len().cmp(&a.peers.len()));
+                        BlockStateHashes {
+                            kind,
+                            height: *height,
+                            variants,
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
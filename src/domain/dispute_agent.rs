@@ -0,0 +1,128 @@
+use crate::{
+    bisq::{NodeAddress, SequencedMessageHash},
+    domain::CommandResult,
+    prelude::*,
+};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+const CHECK_TTL_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisputeAgentRole {
+    Arbitrator,
+    Mediator,
+    RefundAgent,
+}
+
+#[derive(Clone, Debug)]
+pub struct DisputeAgent {
+    pub bisq_hash: SequencedMessageHash,
+    pub role: DisputeAgentRole,
+    pub node_address: Option<NodeAddress>,
+    pub language_codes: Vec<String>,
+    pub registration_date: SystemTime,
+    pub email_address: Option<String>,
+    pub info: Option<String>,
+    // Agents republish their registration, the entry expires if they stop doing so
+    pub expires_at: SystemTime,
+}
+impl DisputeAgent {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.elapsed().is_ok()
+    }
+
+    fn address(&self) -> String {
+        self.node_address
+            .as_ref()
+            .map(NodeAddress::to_string)
+            .unwrap_or_default()
+    }
+}
+
+pub struct AddDisputeAgent(pub DisputeAgent);
+impl Message for AddDisputeAgent {
+    type Result = CommandResult;
+}
+pub struct RemoveDisputeAgent(pub SequencedMessageHash);
+impl Message for RemoveDisputeAgent {
+    type Result = CommandResult;
+}
+pub struct GetDisputeAgents;
+impl Message for GetDisputeAgents {
+    type Result = Arc<HashMap<SequencedMessageHash, DisputeAgent>>;
+}
+
+pub struct DisputeAgentRegistry {
+    agents: Arc<HashMap<SequencedMessageHash, DisputeAgent>>,
+}
+impl Actor for DisputeAgentRegistry {
+    type Context = Context<Self>;
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.run_interval(CHECK_TTL_INTERVAL, |registry, _ctx| {
+            if registry.agents.values().any(DisputeAgent::is_expired) {
+                Arc::make_mut(&mut registry.agents).retain(|_, agent| {
+                    if agent.is_expired() {
+                        info!(
+                            "Registration of {:?} {} expired",
+                            agent.role,
+                            agent.address()
+                        );
+                    }
+                    !agent.is_expired()
+                });
+            }
+        });
+    }
+}
+impl DisputeAgentRegistry {
+    pub fn start() -> Addr<DisputeAgentRegistry> {
+        DisputeAgentRegistry {
+            agents: Arc::new(HashMap::new()),
+        }
+        .start()
+    }
+}
+
+impl Handler<AddDisputeAgent> for DisputeAgentRegistry {
+    type Result = MessageResult<AddDisputeAgent>;
+    fn handle(
+        &mut self,
+        AddDisputeAgent(agent): AddDisputeAgent,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        if agent.is_expired() {
+            return MessageResult(CommandResult::Ignored);
+        }
+        if !self.agents.contains_key(&agent.bisq_hash) {
+            info!("Adding {:?} {}", agent.role, agent.address());
+        }
+        Arc::make_mut(&mut self.agents).insert(agent.bisq_hash, agent);
+        MessageResult(CommandResult::Accepted)
+    }
+}
+impl Handler<RemoveDisputeAgent> for DisputeAgentRegistry {
+    type Result = MessageResult<RemoveDisputeAgent>;
+    fn handle(
+        &mut self,
+        RemoveDisputeAgent(bisq_hash): RemoveDisputeAgent,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        if !self.agents.contains_key(&bisq_hash) {
+            return MessageResult(CommandResult::Ignored);
+        }
+        if let Some(agent) = Arc::make_mut(&mut self.agents).remove(&bisq_hash) {
+            info!("Removing {:?} {}", agent.role, agent.address());
+        }
+        MessageResult(CommandResult::Accepted)
+    }
+}
+impl Handler<GetDisputeAgents> for DisputeAgentRegistry {
+    type Result = MessageResult<GetDisputeAgents>;
+    fn handle(&mut self, _: GetDisputeAgents, _ctx: &mut Self::Context) -> Self::Result {
+        MessageResult(Arc::clone(&self.agents))
+    }
+}
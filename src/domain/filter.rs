@@ -0,0 +1,96 @@
+use crate::{
+    bisq::{NodeAddress, SequencedMessageHash},
+    domain::offer::OpenOffer,
+};
+use std::{
+    sync::{Arc, RwLock},
+    time::SystemTime,
+};
+
+// The filter published by the bisq developers, only the parts risq acts on or reports
+#[derive(Clone, Debug)]
+pub struct ActiveFilter {
+    pub bisq_hash: SequencedMessageHash,
+    pub created_at: SystemTime,
+    pub banned_offer_ids: Vec<String>,
+    pub banned_node_addresses: Vec<String>,
+    pub banned_currencies: Vec<String>,
+    pub banned_payment_methods: Vec<String>,
+    pub disable_dao: bool,
+    pub disable_trade_below_version: Option<String>,
+}
+impl ActiveFilter {
+    pub fn is_node_banned(&self, addr: &NodeAddress) -> bool {
+        let addr = addr.to_string();
+        self.banned_node_addresses
+            .iter()
+            .any(|banned| *banned == addr)
+    }
+
+    // Mirrors isOfferBanned in bisq's FilterManager
+    pub fn is_offer_banned(&self, offer: &OpenOffer) -> bool {
+        let id: &str = offer.id.as_ref();
+        self.banned_offer_ids.iter().any(|banned| banned == id)
+            || offer
+                .maker_node_address
+                .as_ref()
+                .map(|addr| self.is_node_banned(addr))
+                .unwrap_or(false)
+            || self
+                .banned_payment_methods
+                .iter()
+                .any(|banned| *banned == offer.payment_method_id)
+            || self
+                .banned_currencies
+                .iter()
+                .any(|banned| *banned == offer.market.non_btc_side().code)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct FilterCache {
+    active: Arc<RwLock<Option<Arc<ActiveFilter>>>>,
+}
+impl FilterCache {
+    pub fn active(&self) -> Option<Arc<ActiveFilter>> {
+        self.active
+            .read()
+            .expect("Corrupted lock in filter cache")
+            .clone()
+    }
+
+    // Only the newest filter is applied, returns true if it replaced the active one
+    pub fn update(&self, filter: ActiveFilter) -> bool {
+        let mut active = self.active.write().expect("Corrupted lock in filter cache");
+        match active.as_ref() {
+            Some(current) if current.created_at >= filter.created_at => false,
+            _ => {
+                *active = Some(Arc::new(filter));
+                true
+            }
+        }
+    }
+
+    pub fn remove(&self, bisq_hash: SequencedMessageHash) -> bool {
+        let mut active = self.active.write().expect("Corrupted lock in filter cache");
+        match active.as_ref() {
+            Some(current) if current.bisq_hash == bisq_hash => {
+                *active = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_node_banned(&self, addr: &NodeAddress) -> bool {
+        self.active()
+            .map(|filter| filter.is_node_banned(addr))
+            .unwrap_or(false)
+    }
+
+    pub fn is_offer_banned(&self, offer: &OpenOffer) -> bool {
+        self.active()
+            .map(|filter| filter.is_offer_banned(offer))
+            .unwrap_or(false)
+    }
+}
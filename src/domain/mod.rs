@@ -1,8 +1,14 @@
+pub mod account_age;
+pub mod alert;
 pub mod amount;
 pub mod currency;
+pub mod dao;
+pub mod dispute_agent;
+pub mod filter;
 pub mod market;
 pub mod offer;
 pub mod price_feed;
+pub mod signed_witness;
 pub mod statistics;
 
 use crate::prelude::*;
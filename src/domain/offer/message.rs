@@ -19,6 +19,12 @@ impl Message for RefreshOffer {
     type Result = CommandResult;
 }
 
+// Sent when a new filter became active so that banned offers get dropped
+pub struct FilterUpdated;
+impl Message for FilterUpdated {
+    type Result = ();
+}
+
 pub struct GetOpenOffers;
 impl Message for GetOpenOffers {
     type Result = Arc<HashMap<SequencedMessageHash, OpenOffer>>;
@@ -1,7 +1,7 @@
 use super::{message::*, *};
 use crate::{
     bisq::SequencedMessageHash,
-    domain::{price_feed::*, CommandResult},
+    domain::{filter::FilterCache, price_feed::*, CommandResult},
     prelude::*,
 };
 use std::{collections::HashMap, sync::Arc, time::Duration};
@@ -10,8 +10,11 @@ const CHECK_TTL_INTERVAL: Duration = Duration::from_secs(40);
 
 pub struct OfferBook {
     open_offers: Arc<HashMap<SequencedMessageHash, OpenOffer>>,
+    // Set aside until the filter banning them is lifted
+    banned_offers: HashMap<SequencedMessageHash, OpenOffer>,
     price_feed: Addr<PriceFeed>,
     price_data: Arc<HashMap<&'static str, PriceData>>,
+    filter_cache: FilterCache,
 }
 impl Actor for OfferBook {
     type Context = Context<Self>;
@@ -37,6 +40,9 @@ impl Actor for OfferBook {
                             })
                             .collect();
                         offer_book.open_offers = Arc::new(open_offers);
+                        offer_book
+                            .banned_offers
+                            .retain(|_, offer| !offer.is_expired());
                         fut::ok(())
                     },
                 ),
@@ -45,11 +51,13 @@ impl Actor for OfferBook {
     }
 }
 impl OfferBook {
-    pub fn start(price_feed: Addr<PriceFeed>) -> Addr<OfferBook> {
+    pub fn start(price_feed: Addr<PriceFeed>, filter_cache: FilterCache) -> Addr<OfferBook> {
         OfferBook {
             open_offers: Arc::new(HashMap::new()),
+            banned_offers: HashMap::new(),
             price_feed,
             price_data: Arc::new(HashMap::new()),
+            filter_cache,
         }
         .start()
     }
@@ -58,6 +66,18 @@ impl OfferBook {
 impl Handler<AddOffer> for OfferBook {
     type Result = MessageResult<AddOffer>;
     fn handle(&mut self, AddOffer(mut offer): AddOffer, _ctx: &mut Self::Context) -> Self::Result {
+        if self.filter_cache.is_offer_banned(&offer) {
+            let newer = self
+                .banned_offers
+                .get(&offer.bisq_hash)
+                .map(|existing| existing.would_refresh(offer.latest_sequence))
+                .unwrap_or(true);
+            if newer && !offer.is_expired() {
+                info!("Setting aside banned offer {:?}", offer.id);
+                self.banned_offers.insert(offer.bisq_hash, offer);
+            }
+            return MessageResult(CommandResult::Ignored);
+        }
         if !offer.is_expired() {
             offer.update_display_price(&self.price_data);
             match self.open_offers.get(&offer.bisq_hash) {
@@ -85,6 +105,9 @@ impl Handler<RemoveOffer> for OfferBook {
         RemoveOffer(offer): RemoveOffer,
         _ctx: &mut Self::Context,
     ) -> Self::Result {
+        if self.banned_offers.remove(&offer.bisq_hash).is_some() {
+            return MessageResult(CommandResult::Accepted);
+        }
         match self.open_offers.get(&offer.bisq_hash) {
             None => MessageResult(CommandResult::Ignored),
             Some(_) => {
@@ -106,6 +129,11 @@ impl Handler<RefreshOffer> for OfferBook {
         }: RefreshOffer,
         _ctx: &mut Self::Context,
     ) -> Self::Result {
+        if let Some(offer) = self.banned_offers.get_mut(&bisq_hash) {
+            if offer.would_refresh(sequence) && offer.refresh(sequence) {
+                return MessageResult(CommandResult::Accepted);
+            }
+        }
         if let Some(offer) = self.open_offers.get(&bisq_hash) {
             if offer.would_refresh(sequence) {
                 let offers = Arc::make_mut(&mut self.open_offers);
@@ -119,6 +147,48 @@ impl Handler<RefreshOffer> for OfferBook {
     }
 }
 
+impl Handler<FilterUpdated> for OfferBook {
+    type Result = ();
+    fn handle(&mut self, _: FilterUpdated, _ctx: &mut Self::Context) -> Self::Result {
+        let filter = self.filter_cache.active();
+        let is_banned = |offer: &OpenOffer| {
+            filter
+                .as_ref()
+                .map(|filter| filter.is_offer_banned(offer))
+                .unwrap_or(false)
+        };
+        let newly_banned: Vec<SequencedMessageHash> = self
+            .open_offers
+            .iter()
+            .filter(|(_, offer)| is_banned(offer))
+            .map(|(hash, _)| *hash)
+            .collect();
+        let lifted: Vec<SequencedMessageHash> = self
+            .banned_offers
+            .iter()
+            .filter(|(_, offer)| !is_banned(offer))
+            .map(|(hash, _)| *hash)
+            .collect();
+        if newly_banned.is_empty() && lifted.is_empty() {
+            return;
+        }
+        let offers = Arc::make_mut(&mut self.open_offers);
+        for hash in newly_banned {
+            if let Some(offer) = offers.remove(&hash) {
+                info!("Setting aside banned offer {:?}", offer.id);
+                self.banned_offers.insert(hash, offer);
+            }
+        }
+        for hash in lifted {
+            if let Some(mut offer) = self.banned_offers.remove(&hash) {
+                info!("Restoring offer {:?}", offer.id);
+                offer.update_display_price(&self.price_data);
+                offers.insert(hash, offer);
+            }
+        }
+    }
+}
+
 impl Handler<GetOpenOffers> for OfferBook {
     type Result = MessageResult<GetOpenOffers>;
     fn handle(&mut self, _: GetOpenOffers, _ctx: &mut Self::Context) -> Self::Result {
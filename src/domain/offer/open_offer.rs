@@ -1,5 +1,5 @@
 use crate::{
-    bisq::SequencedMessageHash,
+    bisq::{NodeAddress, PersistentMessageHash, SequencedMessageHash},
     domain::{amount::NumberWithPrecision, currency::*, market::Market, price_feed::PriceData},
 };
 use std::{
@@ -23,6 +23,11 @@ impl From<OfferId> for String {
         id.0
     }
 }
+impl AsRef<str> for OfferId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
 
 #[derive(Clone, Copy, Eq, PartialEq, PartialOrd)]
 pub struct OfferSequence(i32);
@@ -67,6 +72,8 @@ pub struct OpenOffer {
     pub amount: OfferAmount,
     pub payment_method_id: String,
     pub offer_fee_tx_id: String,
+    pub maker_node_address: Option<NodeAddress>,
+    pub account_age_witness_hash: Option<PersistentMessageHash>,
     pub created_at: SystemTime,
     pub display_price: NumberWithPrecision,
 
@@ -86,6 +93,8 @@ impl OpenOffer {
         amount: OfferAmount,
         payment_method_id: String,
         offer_fee_tx_id: String,
+        maker_node_address: Option<NodeAddress>,
+        account_age_witness_hash: Option<PersistentMessageHash>,
         created_at: SystemTime,
         sequence: OfferSequence,
     ) -> OpenOffer {
@@ -107,6 +116,8 @@ impl OpenOffer {
             expires_at: created_at + INITIAL_TTL,
             latest_sequence: sequence,
             offer_fee_tx_id,
+            maker_node_address,
+            account_age_witness_hash,
         }
     }
 
@@ -24,6 +24,9 @@ impl Actor for PriceFeed {
     type Context = Context<Self>;
 
     fn started(&mut self, ctx: &mut Self::Context) {
+        if self.nodes.is_empty() {
+            return;
+        }
         self.update_prices(ctx);
         ctx.run_interval(*LOOP_INTERVAL, |feed, ctx| feed.update_prices(ctx));
     }
@@ -48,6 +51,15 @@ impl PriceFeed {
         }
         .start()
     }
+    // A feed that never queries the price nodes, used when replaying recordings
+    pub fn offline() -> Addr<PriceFeed> {
+        PriceFeed {
+            client: Client::new(),
+            price_data: Arc::new(HashMap::new()),
+            nodes: Vec::new(),
+        }
+        .start()
+    }
     fn update_prices(&mut self, ctx: &mut Context<Self>) {
         let node_index: usize = thread_rng().gen::<usize>() % self.nodes.len();
         let url = format!("{}/getAllMarketPrices", self.nodes[node_index]);
@@ -0,0 +1,153 @@
+use crate::{
+    bisq::{
+        constants::{arbitrator_pub_keys, BaseCurrencyNetwork},
+        PersistentMessageHash,
+    },
+    prelude::FromHex,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+// A peer can only sign other accounts once its own signature is this old
+const SIGNER_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+const LIMIT_LIFTED_AGE: Duration = Duration::from_secs(60 * 24 * 60 * 60);
+// Same limit bisq applies when walking the signing chain
+const MAX_CHAIN_LENGTH: usize = 2000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AccountSignState {
+    Arbitrator,
+    PeerInitial,
+    PeerLimitLifted,
+    PeerSigner,
+    Unsigned,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AccountSigning {
+    pub witness_hash: PersistentMessageHash,
+    pub state: AccountSignState,
+    pub signed_at: Option<SystemTime>,
+}
+
+// A SignedWitness whose signature has already been verified
+#[derive(Clone, Debug)]
+pub struct WitnessSignature {
+    pub account_age_witness_hash: PersistentMessageHash,
+    pub signer_pub_key: Vec<u8>,
+    pub owner_pub_key: Vec<u8>,
+    pub date: SystemTime,
+    pub by_arbitrator: bool,
+}
+
+struct Signatures {
+    all: Vec<WitnessSignature>,
+    by_account: HashMap<PersistentMessageHash, Vec<usize>>,
+    by_owner: HashMap<Vec<u8>, Vec<usize>>,
+    arbitrator_keys: HashSet<Vec<u8>>,
+}
+impl Signatures {
+    // Mirrors isValidSignerWitnessInternal in bisq's SignedWitnessService
+    fn is_valid(&self, idx: usize, child_date: SystemTime, excluded: &mut Vec<Vec<u8>>) -> bool {
+        let signature = &self.all[idx];
+        if signature.by_arbitrator {
+            return self.arbitrator_keys.contains(&signature.signer_pub_key);
+        }
+        if signature.date + SIGNER_AGE > child_date || excluded.len() >= MAX_CHAIN_LENGTH {
+            return false;
+        }
+        excluded.push(signature.signer_pub_key.clone());
+        excluded.push(signature.owner_pub_key.clone());
+        let mut valid = false;
+        if let Some(signers) = self.by_owner.get(&signature.signer_pub_key) {
+            for signer in signers.iter() {
+                if !excluded.contains(&self.all[*signer].signer_pub_key)
+                    && self.is_valid(*signer, signature.date, excluded)
+                {
+                    valid = true;
+                    break;
+                }
+            }
+        }
+        excluded.pop();
+        excluded.pop();
+        valid
+    }
+}
+
+#[derive(Clone)]
+pub struct SignedWitnessCache {
+    signatures: Arc<RwLock<Signatures>>,
+}
+impl SignedWitnessCache {
+    pub fn new(network: BaseCurrencyNetwork) -> Self {
+        Self {
+            signatures: Arc::new(RwLock::new(Signatures {
+                all: Vec::new(),
+                by_account: HashMap::new(),
+                by_owner: HashMap::new(),
+                arbitrator_keys: arbitrator_pub_keys(network)
+                    .iter()
+                    .filter_map(|key| Vec::<u8>::from_hex(key).ok())
+                    .collect(),
+            })),
+        }
+    }
+
+    pub fn add(&self, signature: WitnessSignature) {
+        let mut signatures = self
+            .signatures
+            .write()
+            .expect("Corrupted lock in signed witness cache");
+        let idx = signatures.all.len();
+        signatures
+            .by_account
+            .entry(signature.account_age_witness_hash)
+            .or_insert_with(Vec::new)
+            .push(idx);
+        signatures
+            .by_owner
+            .entry(signature.owner_pub_key.clone())
+            .or_insert_with(Vec::new)
+            .push(idx);
+        signatures.all.push(signature);
+    }
+
+    // The sign state bisq derives its trade limits from
+    pub fn signing(&self, witness_hash: PersistentMessageHash) -> AccountSigning {
+        let signatures = self
+            .signatures
+            .read()
+            .expect("Corrupted lock in signed witness cache");
+        let now = SystemTime::now();
+        let valid: Vec<&WitnessSignature> = signatures
+            .by_account
+            .get(&witness_hash)
+            .map(|idxs| {
+                idxs.iter()
+                    .filter(|idx| signatures.is_valid(**idx, now + SIGNER_AGE, &mut Vec::new()))
+                    .map(|idx| &signatures.all[*idx])
+                    .collect()
+            })
+            .unwrap_or_default();
+        let signed_at = valid.iter().map(|signature| signature.date).min();
+        let state = if valid.iter().any(|signature| signature.by_arbitrator) {
+            AccountSignState::Arbitrator
+        } else {
+            match signed_at.map(|date| now.duration_since(date).unwrap_or_default()) {
+                None => AccountSignState::Unsigned,
+                Some(age) if age < SIGNER_AGE => AccountSignState::PeerInitial,
+                Some(age) if age < LIMIT_LIFTED_AGE => AccountSignState::PeerLimitLifted,
+                Some(_) => AccountSignState::PeerSigner,
+            }
+        };
+        AccountSigning {
+            witness_hash,
+            state,
+            signed_at,
+        }
+    }
+}
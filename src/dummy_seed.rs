@@ -3,43 +3,125 @@ use crate::{
         constants::{BaseCurrencyNetwork, LOCAL_CAPABILITIES},
         payload::*,
     },
+    domain::filter::FilterCache,
     p2p::{
         dispatch::*, message::Direct, server, BootstrapState, Broadcaster, ConnectionId, Peers,
         Status,
     },
     prelude::*,
 };
-use std::path::Path;
+use serde::Deserialize;
+use std::{
+    collections::HashSet,
+    fs::File,
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::timer::Delay;
+
+/// Data served by the dummy seed, read from a json file.
+/// The timeline is replayed to every node that completes its initial data request.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct Fixtures {
+    data_set: Vec<StorageEntryWrapper>,
+    persistable_network_payload_items: Vec<PersistableNetworkPayload>,
+    timeline: Vec<TimelineEvent>,
+}
+#[derive(Deserialize)]
+struct TimelineEvent {
+    after_ms: u64,
+    message: network_envelope::Message,
+}
+impl Fixtures {
+    fn load(path: &Path) -> Self {
+        let file = File::open(path).expect("Couldn't open fixtures");
+        serde_json::from_reader(file).expect("Couldn't parse fixtures")
+    }
+
+    fn response(
+        &self,
+        request_nonce: i32,
+        is_get_updated_data_response: bool,
+        excluded_keys: Vec<Vec<u8>>,
+    ) -> GetDataResponse {
+        let excluded_keys: HashSet<Vec<u8>> = excluded_keys.into_iter().collect();
+        GetDataResponse {
+            request_nonce,
+            is_get_updated_data_response,
+            data_set: self
+                .data_set
+                .iter()
+                .filter(|wrapper| {
+                    storage_payload(wrapper)
+                        .map(|payload| !excluded_keys.contains(&Vec::from(payload.bisq_hash())))
+                        .unwrap_or(false)
+                })
+                .cloned()
+                .collect(),
+            supported_capabilities: LOCAL_CAPABILITIES.clone(),
+            persistable_network_payload_items: self
+                .persistable_network_payload_items
+                .iter()
+                .filter(|payload| !excluded_keys.contains(&Vec::from(payload.bisq_hash())))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+fn storage_payload(wrapper: &StorageEntryWrapper) -> Option<&StoragePayload> {
+    match wrapper.message.as_ref()? {
+        storage_entry_wrapper::Message::ProtectedStorageEntry(entry) => {
+            entry.storage_payload.as_ref()
+        }
+        storage_entry_wrapper::Message::ProtectedMailboxStorageEntry(entry) => {
+            entry.entry.as_ref()?.storage_payload.as_ref()
+        }
+    }
+}
 
 #[derive(Clone)]
-struct SeedDataResponder(Addr<Broadcaster>);
+struct SeedDataResponder {
+    broadcaster: Addr<Broadcaster>,
+    fixtures: Arc<Fixtures>,
+}
+impl SeedDataResponder {
+    fn play_timeline(&self, conn: ConnectionId) {
+        let start = Instant::now();
+        for event in self.fixtures.timeline.iter() {
+            let broadcaster = self.broadcaster.clone();
+            let message = event.message.clone();
+            Arbiter::spawn(
+                Delay::new(start + Duration::from_millis(event.after_ms))
+                    .map_err(|e| error!("Timeline delay failed: {:?}", e))
+                    .and_then(move |_| {
+                        broadcaster
+                            .send(Direct(message, conn))
+                            .map_err(|e| error!("Couldn't send timeline event: {:?}", e))
+                    }),
+            );
+        }
+    }
+}
 impl Dispatcher for SeedDataResponder {
     fn dispatch(&self, conn: ConnectionId, msg: network_envelope::Message) -> Dispatch {
         match msg {
             network_envelope::Message::PreliminaryGetDataRequest(request) => {
-                arbiter_spawn!(self.0.send(Direct(
-                    GetDataResponse {
-                        request_nonce: request.nonce,
-                        is_get_updated_data_response: false,
-                        data_set: Vec::new(),
-                        supported_capabilities: LOCAL_CAPABILITIES.clone(),
-                        persistable_network_payload_items: Vec::new(),
-                    },
+                arbiter_spawn!(self.broadcaster.send(Direct(
+                    self.fixtures
+                        .response(request.nonce, false, request.excluded_keys),
                     conn
                 )));
                 Dispatch::Consumed
             }
             network_envelope::Message::GetUpdatedDataRequest(request) => {
-                arbiter_spawn!(self.0.send(Direct(
-                    GetDataResponse {
-                        request_nonce: request.nonce,
-                        is_get_updated_data_response: true,
-                        data_set: Vec::new(),
-                        supported_capabilities: LOCAL_CAPABILITIES.clone(),
-                        persistable_network_payload_items: Vec::new(),
-                    },
+                arbiter_spawn!(self.broadcaster.send(Direct(
+                    self.fixtures
+                        .response(request.nonce, true, request.excluded_keys),
                     conn
                 )));
+                self.play_timeline(conn);
                 Dispatch::Consumed
             }
             _ => Dispatch::Retained(msg),
@@ -47,7 +129,8 @@ impl Dispatcher for SeedDataResponder {
     }
 }
 
-pub fn run(server_port: u16, _fixtures: Option<&Path>) {
+pub fn run(server_port: u16, fixtures: Option<&Path>) {
+    let fixtures = Arc::new(fixtures.map(Fixtures::load).unwrap_or_default());
     let sys = System::new("risq");
 
     let network = BaseCurrencyNetwork::BtcRegtest;
@@ -58,12 +141,18 @@ pub fn run(server_port: u16, _fixtures: Option<&Path>) {
     let peers = Peers::start(
         network,
         broadcaster.clone(),
-        p2p_status,
-        SeedDataResponder(broadcaster),
+        p2p_status.clone(),
+        FilterCache::default(),
+        SeedDataResponder {
+            broadcaster,
+            fixtures,
+        },
+        None,
         None,
+        true,
     );
 
-    server::start(server_port, peers, None, None);
+    server::start(server_port, peers, None, p2p_status, None);
 
     let _ = sys.run();
 }
@@ -16,7 +16,9 @@ pub enum Error {
     ReceiveOneshotError,
     MailboxError(MailboxError),
     SendMPSCError,
+    MPSCFull,
     ReceiveMPSCError,
+    Timeout,
 }
 
 impl From<io::Error> for Error {
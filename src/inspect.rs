@@ -0,0 +1,273 @@
+use crate::{
+    bisq::{constants::Capability, payload::*},
+    prelude::{FromHex, ToHex},
+};
+use prost::{
+    encoding::{decode_varint, encoded_len_varint},
+    Message,
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::{convert::TryFrom, fs, io::Cursor, path::Path, str::FromStr};
+
+pub enum Encoding {
+    Hex,
+    Base64,
+    Raw,
+}
+impl FromStr for Encoding {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hex" => Ok(Encoding::Hex),
+            "base64" => Ok(Encoding::Base64),
+            "raw" => Ok(Encoding::Raw),
+            _ => Err("(hex|base64|raw)".into()),
+        }
+    }
+}
+
+pub enum Kind {
+    NetworkEnvelope,
+    StoragePayload,
+    PersistableEnvelope,
+    ProtectedStorageEntry,
+}
+impl FromStr for Kind {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "network-envelope" => Ok(Kind::NetworkEnvelope),
+            "storage-payload" => Ok(Kind::StoragePayload),
+            "persistable-envelope" => Ok(Kind::PersistableEnvelope),
+            "protected-storage-entry" => Ok(Kind::ProtectedStorageEntry),
+            _ => Err(
+                "(network-envelope|storage-payload|persistable-envelope|protected-storage-entry)"
+                    .into(),
+            ),
+        }
+    }
+}
+
+// For hex and base64 the input is the encoded data itself, for raw it is a file path
+pub fn inspect(kind: Kind, encoding: Encoding, input: &str) -> Result<(), String> {
+    let bytes = match encoding {
+        Encoding::Hex => Vec::<u8>::from_hex(input.trim())
+            .map_err(|e| format!("Couldn't decode hex: {:?}", e))?,
+        Encoding::Base64 => {
+            base64::decode(input.trim()).map_err(|e| format!("Couldn't decode base64: {:?}", e))?
+        }
+        Encoding::Raw => fs::read(Path::new(input)).map_err(|e| format!("{}", e))?,
+    };
+    let bytes = strip_length_prefix(bytes);
+    match kind {
+        Kind::NetworkEnvelope => {
+            let envelope = decode::<NetworkEnvelope>(bytes)?;
+            print_json(&envelope)?;
+            if let Some(message) = envelope.message.as_ref() {
+                print_network_message(message);
+            }
+        }
+        Kind::StoragePayload => {
+            let payload = decode::<StoragePayload>(bytes)?;
+            print_json(&payload)?;
+            println!("bisq_hash: {}", hash_hex(payload.bisq_hash()));
+        }
+        Kind::PersistableEnvelope => {
+            let envelope = decode::<PersistableEnvelope>(bytes)?;
+            print_json(&envelope)?;
+        }
+        Kind::ProtectedStorageEntry => {
+            let entry = decode::<ProtectedStorageEntry>(bytes)?;
+            print_json(&entry)?;
+            print_entry(&entry);
+        }
+    }
+    Ok(())
+}
+
+// Bisq frames envelopes on the wire and in its stores with a varint length prefix.
+// Only strip it if the prefix matches the remaining length exactly.
+fn strip_length_prefix(bytes: Vec<u8>) -> Vec<u8> {
+    let mut buf = Cursor::new(&bytes);
+    match decode_varint(&mut buf) {
+        Ok(len) if len > 0 && encoded_len_varint(len) + len as usize == bytes.len() => {
+            bytes[buf.position() as usize..].to_vec()
+        }
+        _ => bytes,
+    }
+}
+
+fn decode<M: Message + Default>(bytes: Vec<u8>) -> Result<M, String> {
+    M::decode(bytes).map_err(|e| format!("Couldn't decode: {}", e))
+}
+
+fn print_json<T: Serialize>(msg: &T) -> Result<(), String> {
+    let mut value = serde_json::to_value(msg).map_err(|e| format!("{}", e))?;
+    bytes_to_hex(&mut value);
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&value).map_err(|e| format!("{}", e))?
+    );
+    extra_data_lines(&value)
+        .iter()
+        .for_each(|line| println!("{}", line));
+    Ok(())
+}
+
+// Serde renders bytes fields as arrays of numbers. Keys, signatures and hashes are
+// always longer than any repeated integer field bisq sends, so show those as hex.
+const MIN_HEX_LEN: usize = 20;
+fn bytes_to_hex(value: &mut Value) {
+    match value {
+        Value::Object(map) => map.values_mut().for_each(bytes_to_hex),
+        Value::Array(values) => {
+            let bytes: Option<Vec<u8>> = values
+                .iter()
+                .map(|v| v.as_u64().filter(|n| *n <= 255).map(|n| n as u8))
+                .collect();
+            match bytes {
+                Some(ref bytes) if bytes.len() >= MIN_HEX_LEN => {
+                    *value = Value::String(bytes.to_hex())
+                }
+                _ => values.iter_mut().for_each(bytes_to_hex),
+            }
+        }
+        _ => (),
+    }
+}
+
+fn print_network_message(message: &network_envelope::Message) {
+    match message {
+        network_envelope::Message::AddDataMessage(msg) => {
+            match msg.entry.as_ref().and_then(|e| e.message.as_ref()) {
+                Some(storage_entry_wrapper::Message::ProtectedStorageEntry(entry)) => {
+                    print_entry(entry)
+                }
+                Some(storage_entry_wrapper::Message::ProtectedMailboxStorageEntry(mailbox)) => {
+                    if let Some(entry) = mailbox.entry.as_ref() {
+                        print_entry(entry)
+                    }
+                }
+                None => (),
+            }
+        }
+        network_envelope::Message::AddPersistableNetworkPayloadMessage(msg) => {
+            if let Some(payload) = msg.payload.as_ref() {
+                println!("bisq_hash: {}", hash_hex(payload.bisq_hash()));
+            }
+        }
+        network_envelope::Message::RefreshOfferMessage(msg) => {
+            println!("bisq_hash: {}", hash_hex(msg.payload_hash()));
+        }
+        _ => (),
+    }
+}
+
+fn print_entry(entry: &ProtectedStorageEntry) {
+    if let Some(payload) = entry.storage_payload.as_ref() {
+        println!("bisq_hash: {}", hash_hex(payload.bisq_hash()));
+    }
+    let verified = entry.verify().is_some();
+    println!("verify: {}", if verified { "ok" } else { "FAILED" });
+}
+
+fn hash_hex<H: Into<Vec<u8>>>(hash: H) -> String {
+    hash.into().to_hex()
+}
+
+// extraData is a string map in bisq, we serialize entries as [key, value] pairs
+fn extra_data_lines(value: &Value) -> Vec<String> {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .flat_map(|(key, value)| {
+                if key == "extra_data" {
+                    value
+                        .as_array()
+                        .map(|entries| entries.iter().filter_map(extra_data_line).collect())
+                        .unwrap_or_default()
+                } else {
+                    extra_data_lines(value)
+                }
+            })
+            .collect(),
+        Value::Array(values) => values.iter().flat_map(extra_data_lines).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn extra_data_line(entry: &Value) -> Option<String> {
+    let (key, value) = match entry.as_array().map(Vec::as_slice) {
+        Some([Value::String(key), Value::String(value)]) => (key, value),
+        _ => return None,
+    };
+    Some(match key.as_str() {
+        "capabilities" => {
+            let capabilities: Vec<String> = value
+                .split(',')
+                .map(|n| {
+                    match n
+                        .trim()
+                        .parse::<i32>()
+                        .ok()
+                        .and_then(|n| Capability::try_from(n).ok())
+                    {
+                        Some(capability) => format!("{:?}", capability),
+                        None => format!("Unknown({})", n),
+                    }
+                })
+                .collect();
+            format!("extra_data.capabilities: {}", capabilities.join(", "))
+        }
+        _ => format!("extra_data.{}: {}", key, value),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bisq::payload::tests::BISQ_HEX;
+
+    fn prefixed(bytes: &[u8]) -> Vec<u8> {
+        let mut prefixed = Vec::new();
+        prost::encoding::encode_varint(bytes.len() as u64, &mut prefixed);
+        prefixed.extend_from_slice(bytes);
+        prefixed
+    }
+
+    #[test]
+    fn strips_length_prefix() {
+        assert_eq!(strip_length_prefix(BISQ_HEX.to_vec()), BISQ_HEX);
+        assert_eq!(strip_length_prefix(prefixed(BISQ_HEX)), BISQ_HEX);
+        // A prefix that doesn't match the length is part of the message
+        let mut truncated = prefixed(BISQ_HEX);
+        truncated.pop();
+        assert_eq!(strip_length_prefix(truncated.clone()), truncated);
+    }
+
+    #[test]
+    fn renders_offer() {
+        for input in vec![BISQ_HEX.to_vec(), prefixed(BISQ_HEX)] {
+            let payload = decode::<StoragePayload>(strip_length_prefix(input)).unwrap();
+            let mut value = serde_json::to_value(&payload).unwrap();
+            bytes_to_hex(&mut value);
+            let key = &value["message"]["OfferPayload"]["pub_key_ring"]["signature_pub_key_bytes"];
+            assert!(key
+                .as_str()
+                .map(|hex| Vec::<u8>::from_hex(hex).is_ok())
+                .unwrap_or(false));
+            assert_eq!(
+                extra_data_lines(&value),
+                vec![
+                    "extra_data.capabilities: TradeStatistics, TradeStatistics2, AccountAgeWitness, \
+                     Proposal, BlindVote, AckMsg, ReceiveBsqBlock, DaoState, BundleOfEnvelopes, \
+                     Mediation",
+                    "extra_data.accountAgeWitnessHash: cb5dcad7fddb02cf77665280a3d9c756f83c634c",
+                    "extra_data.f2fExtraInfo: ",
+                    "extra_data.f2fCity: LUANG PRABANG",
+                ]
+            );
+        }
+    }
+}
@@ -16,6 +16,8 @@ mod domain;
 #[cfg(feature = "dummy-seed")]
 mod dummy_seed;
 mod error;
+#[cfg(feature = "inspect")]
+mod inspect;
 mod p2p;
 
 #[cfg(not(target_os = "android"))]
@@ -24,7 +26,7 @@ pub mod cli;
 #[macro_use]
 extern crate log;
 
-#[cfg(target_os = "android")]
 pub use bisq::constants::BaseCurrencyNetwork;
+pub use daemon::import_bisq_data_dir;
 #[cfg(target_os = "android")]
 pub use daemon::*;
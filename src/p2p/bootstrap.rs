@@ -10,31 +10,65 @@ use crate::{
         payload::*,
     },
     error::Error,
-    prelude::{sync::oneshot, *},
+    prelude::{
+        future::{Either, Shared},
+        sync::oneshot,
+        *,
+    },
 };
 use rand::{seq::SliceRandom, thread_rng};
 use std::{
-    fmt,
+    cmp, fmt,
     sync::{Arc, RwLock},
+    time::Duration,
 };
 
-#[derive(Clone, Copy, PartialEq)]
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(90);
+const RETRY_BACKOFF: Duration = Duration::from_secs(5);
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(300);
+
+#[derive(Clone, PartialEq)]
 pub enum BootstrapState {
     PreBootstrap,
-    InitialBootstrapInProgress,
-    Bootstrapped,
+    InitialBootstrapInProgress {
+        seed: Option<NodeAddress>,
+        failed_attempts: u32,
+    },
+    Bootstrapped {
+        seed: Option<NodeAddress>,
+        failed_attempts: u32,
+    },
 }
 impl BootstrapState {
     pub fn init() -> Arc<RwLock<BootstrapState>> {
         Arc::new(RwLock::new(BootstrapState::PreBootstrap))
     }
+    pub fn seed(&self) -> Option<&NodeAddress> {
+        match self {
+            Self::PreBootstrap => None,
+            Self::InitialBootstrapInProgress { seed, .. } | Self::Bootstrapped { seed, .. } => {
+                seed.as_ref()
+            }
+        }
+    }
+    pub fn failed_attempts(&self) -> u32 {
+        match self {
+            Self::PreBootstrap => 0,
+            Self::InitialBootstrapInProgress {
+                failed_attempts, ..
+            }
+            | Self::Bootstrapped {
+                failed_attempts, ..
+            } => *failed_attempts,
+        }
+    }
 }
 impl fmt::Display for BootstrapState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::PreBootstrap => write!(f, "PreBootstrap"),
-            Self::InitialBootstrapInProgress => write!(f, "InitialBootstrapInProgress"),
-            Self::Bootstrapped => write!(f, "Running"),
+            Self::InitialBootstrapInProgress { .. } => write!(f, "InitialBootstrapInProgress"),
+            Self::Bootstrapped { .. } => write!(f, "Running"),
         }
     }
 }
@@ -44,45 +78,39 @@ pub struct Bootstrap<D: SendableDispatcher> {
     state: Arc<RwLock<BootstrapState>>,
     proxy_port: Option<u16>,
     addr_notify: Option<oneshot::Sender<NodeAddress>>,
-    addr_rec: Option<oneshot::Receiver<NodeAddress>>,
+    local_addr: Shared<oneshot::Receiver<NodeAddress>>,
     seed_nodes: Vec<NodeAddress>,
+    next_seed: usize,
+    failed_attempts: u32,
+    known_keys: Vec<Vec<u8>>,
     peers: Addr<Peers<D>>,
     dispatcher: D,
+    seed_node: bool,
 }
 impl<D: SendableDispatcher> Actor for Bootstrap<D> {
     type Context = Context<Bootstrap<D>>;
     fn started(&mut self, ctx: &mut Self::Context) {
-        *self.state.write().expect("Corrupted lock in bootstrap") =
-            BootstrapState::InitialBootstrapInProgress;
-
-        let addr = self.seed_nodes.pop().expect("No seed nodes defined");
+        if !self.seed_node {
+            self.try_next_seed(ctx);
+            return;
+        }
+        // A seed node must not try to bootstrap from itself
         ctx.spawn(
-            fut::wrap_future(bootstrap_from_seed(
-                addr.clone(),
-                self.addr_rec.take().expect("Receiver already removed"),
-                self.network,
-                self.dispatcher.clone(),
-                self.proxy_port,
-            ))
-            .map_err(|_, _, _| ())
-            .and_then(move |seed_result, bootstrap: &mut Bootstrap<D>, _ctx| {
-                *bootstrap
-                    .state
-                    .write()
-                    .expect("Corrupted lock in bootstrap") = BootstrapState::Bootstrapped;
-
-                fut::wrap_future(
-                    bootstrap
-                        .peers
-                        .send(SeedConnection(
-                            addr,
-                            seed_result.connection_id,
-                            seed_result.connection,
-                        ))
-                        .map_err(|_| ()),
-                )
-            })
-            .map(|_, _, ctx| ctx.stop()),
+            fut::wrap_future(self.local_addr.clone())
+                .map_err(|_, _, _| error!("Local address not received"))
+                .map(|local_addr, bootstrap: &mut Self, ctx| {
+                    bootstrap.seed_nodes.retain(|addr| addr != &*local_addr);
+                    if bootstrap.seed_nodes.is_empty() {
+                        info!("No other seed nodes to bootstrap from");
+                        bootstrap.set_state(BootstrapState::Bootstrapped {
+                            seed: None,
+                            failed_attempts: 0,
+                        });
+                        ctx.stop();
+                    } else {
+                        bootstrap.try_next_seed(ctx);
+                    }
+                }),
         );
     }
 }
@@ -105,51 +133,217 @@ impl<D: SendableDispatcher> Bootstrap<D> {
         dispatcher: D,
         proxy_port: Option<u16>,
         force_seed: Option<NodeAddress>,
+        additional_seeds: Vec<NodeAddress>,
+        known_keys: Vec<Vec<u8>>,
+        seed_node: bool,
     ) -> Addr<Bootstrap<D>> {
-        let mut seed_nodes = match force_seed {
+        let seed_nodes = match force_seed {
             Some(addr) => vec![addr],
-            None => seed_nodes(network),
+            None => {
+                let mut defaults = seed_nodes(network);
+                defaults.shuffle(&mut thread_rng());
+                let mut seed_nodes = additional_seeds;
+                defaults.into_iter().for_each(|addr| {
+                    if !seed_nodes.contains(&addr) {
+                        seed_nodes.push(addr)
+                    }
+                });
+                seed_nodes
+            }
         };
-        seed_nodes.shuffle(&mut thread_rng());
         let (addr_notify, addr_rec) = oneshot::channel();
         Self {
             network,
             addr_notify: Some(addr_notify),
-            addr_rec: Some(addr_rec),
+            local_addr: addr_rec.shared(),
             proxy_port,
             seed_nodes,
+            next_seed: 0,
+            failed_attempts: 0,
+            known_keys,
             peers,
             dispatcher,
             state,
+            seed_node,
         }
         .start()
     }
+
+    fn next_seed(&mut self) -> NodeAddress {
+        let addr = self.seed_nodes[self.next_seed % self.seed_nodes.len()].clone();
+        self.next_seed += 1;
+        addr
+    }
+
+    fn set_state(&self, state: BootstrapState) {
+        *self.state.write().expect("Corrupted lock in bootstrap") = state;
+    }
+
+    fn bootstrap_from(
+        &self,
+        addr: NodeAddress,
+    ) -> impl ActorFuture<Item = SeedResult, Error = Error, Actor = Self> {
+        fut::wrap_future(bootstrap_from_seed(
+            addr,
+            self.local_addr.clone(),
+            self.network,
+            self.dispatcher.clone(),
+            self.proxy_port,
+            self.known_keys.clone(),
+        ))
+    }
+
+    fn try_next_seed(&mut self, ctx: &mut <Self as Actor>::Context) {
+        let addr = self.next_seed();
+        self.set_state(BootstrapState::InitialBootstrapInProgress {
+            seed: Some(addr.clone()),
+            failed_attempts: self.failed_attempts,
+        });
+        ctx.spawn(self.bootstrap_from(addr.clone()).then(
+            move |result, bootstrap: &mut Self, ctx| {
+                match result {
+                    Ok(seed_result) => {
+                        bootstrap.set_state(BootstrapState::Bootstrapped {
+                            seed: Some(addr.clone()),
+                            failed_attempts: bootstrap.failed_attempts,
+                        });
+                        bootstrap.seed_connected(addr, seed_result);
+                        bootstrap.merge_from_next_seed(ctx);
+                    }
+                    Err(e) => {
+                        warn!("Bootstrapping from seed {} failed: {:?}", addr, e);
+                        bootstrap.failed_attempts += 1;
+                        bootstrap.set_state(BootstrapState::InitialBootstrapInProgress {
+                            seed: None,
+                            failed_attempts: bootstrap.failed_attempts,
+                        });
+                        let backoff = cmp::min(
+                            RETRY_BACKOFF * 2u32.pow(cmp::min(bootstrap.failed_attempts - 1, 6)),
+                            MAX_RETRY_BACKOFF,
+                        );
+                        info!("Retrying bootstrap in {:?}", backoff);
+                        ctx.run_later(backoff, |bootstrap, ctx| bootstrap.try_next_seed(ctx));
+                    }
+                }
+                fut::ok(())
+            },
+        ));
+    }
+
+    // Ask a second seed for anything the first one didn't know about
+    fn merge_from_next_seed(&mut self, ctx: &mut <Self as Actor>::Context) {
+        if self.seed_nodes.len() < 2 {
+            ctx.stop();
+            return;
+        }
+        let addr = self.next_seed();
+        ctx.spawn(self.bootstrap_from(addr.clone()).then(
+            move |result, bootstrap: &mut Self, ctx| {
+                match result {
+                    Ok(seed_result) => bootstrap.seed_connected(addr, seed_result),
+                    Err(e) => warn!("Merging data from seed {} failed: {:?}", addr, e),
+                }
+                ctx.stop();
+                fut::ok(())
+            },
+        ));
+    }
+
+    fn seed_connected(&mut self, addr: NodeAddress, seed_result: SeedResult) {
+        self.known_keys = seed_result.known_keys;
+        arbiter_spawn!(self.peers.send(SeedConnection(
+            addr,
+            seed_result.connection_id,
+            seed_result.connection,
+        )));
+    }
 }
 struct SeedResult {
     connection: Addr<Connection>,
     connection_id: ConnectionId,
+    known_keys: Vec<Vec<u8>>,
 }
 fn bootstrap_from_seed<D: SendableDispatcher>(
     seed_addr: NodeAddress,
-    local_addr: oneshot::Receiver<NodeAddress>,
+    local_addr: Shared<oneshot::Receiver<NodeAddress>>,
     network: BaseCurrencyNetwork,
     dispatcher: D,
     proxy_port: Option<u16>,
+    known_keys: Vec<Vec<u8>>,
 ) -> impl Future<Item = SeedResult, Error = Error> {
+    info!("Bootstrapping from seed: {:?}", seed_addr);
+    with_timeout(Connection::open(
+        seed_addr,
+        network.into(),
+        dispatcher.clone(),
+        proxy_port,
+    ))
+    .and_then(move |(id, conn)| {
+        if known_keys.is_empty() {
+            Either::A(preliminary_get_data(id, conn, dispatcher))
+        } else {
+            debug!(
+                "Skipping PreliminaryGetDataRequest, {} keys are already known.",
+                known_keys.len()
+            );
+            Either::B(future::ok((id, conn, dispatcher, known_keys)))
+        }
+    })
+    .and_then(move |(id, conn, dispatcher, excluded_keys)| {
+        local_addr
+            .map(move |addr| {
+                (
+                    GetUpdatedDataRequest {
+                        sender_node_address: Some((*addr).clone()),
+                        nonce: gen_nonce(),
+                        excluded_keys,
+                    },
+                    id,
+                    conn,
+                    dispatcher,
+                )
+            })
+            .map_err(|_| Error::ReceiveOneshotError)
+    })
+    .and_then(|(request, id, conn, dispatcher)| {
+        debug!("Sending GetUpdatedDataRequest to seed.");
+        let mut known_keys = request.excluded_keys.clone();
+        with_timeout(conn.send(Request(request)).flatten()).map(move |get_updated_data_response| {
+            debug!(
+                "Update data response has {} items",
+                get_updated_data_response.data_set.len()
+                    + get_updated_data_response
+                        .persistable_network_payload_items
+                        .len()
+            );
+            known_keys.extend(get_response_keys(&get_updated_data_response));
+            dispatcher.dispatch(id, get_updated_data_response.into());
+            SeedResult {
+                connection_id: id,
+                connection: conn,
+                known_keys,
+            }
+        })
+    })
+}
+fn with_timeout<F: Future<Error = Error>>(future: F) -> impl Future<Item = F::Item, Error = Error> {
+    future
+        .timeout(REQUEST_TIMEOUT)
+        .map_err(|e| e.into_inner().unwrap_or(Error::Timeout))
+}
+fn preliminary_get_data<D: SendableDispatcher>(
+    id: ConnectionId,
+    conn: Addr<Connection>,
+    dispatcher: D,
+) -> impl Future<Item = (ConnectionId, Addr<Connection>, D, Vec<Vec<u8>>), Error = Error> {
     let preliminary_get_data_request = PreliminaryGetDataRequest {
         nonce: gen_nonce(),
         excluded_keys: Vec::new(),
         supported_capabilities: LOCAL_CAPABILITIES.clone(),
     };
-    info!("Bootstrapping from seed: {:?}", seed_addr);
-    Connection::open(seed_addr, network.into(), dispatcher.clone(), proxy_port)
-        .and_then(|(id, conn)| {
-            debug!("Sending PreliminaryGetDataRequest to seed.");
-            conn.send(Request(preliminary_get_data_request))
-                .flatten()
-                .map(move |response| (id, conn, response))
-        })
-        .and_then(move |(id, conn, preliminary_data_response)| {
+    debug!("Sending PreliminaryGetDataRequest to seed.");
+    with_timeout(conn.send(Request(preliminary_get_data_request)).flatten()).map(
+        move |preliminary_data_response| {
             debug!(
                 "Preliminary data response has {} items",
                 preliminary_data_response.data_set.len()
@@ -157,46 +351,14 @@ fn bootstrap_from_seed<D: SendableDispatcher>(
                         .persistable_network_payload_items
                         .len()
             );
-            let excluded_keys = get_excluded_keys(&preliminary_data_response);
+            let excluded_keys = get_response_keys(&preliminary_data_response);
             dispatcher.dispatch(id, preliminary_data_response.into());
-
-            local_addr
-                .map(move |addr| {
-                    (
-                        GetUpdatedDataRequest {
-                            sender_node_address: addr.into(),
-                            nonce: gen_nonce(),
-                            excluded_keys,
-                        },
-                        id,
-                        conn,
-                        dispatcher,
-                    )
-                })
-                .map_err(|e| e.into())
-        })
-        .and_then(|(request, id, conn, dispatcher)| {
-            debug!("Sending GetUpdatedDataRequest to seed.");
-            conn.send(Request(request))
-                .flatten()
-                .map(move |get_updated_data_response| {
-                    debug!(
-                        "Update data response has {} items",
-                        get_updated_data_response.data_set.len()
-                            + get_updated_data_response
-                                .persistable_network_payload_items
-                                .len()
-                    );
-                    dispatcher.dispatch(id, get_updated_data_response.into());
-                    SeedResult {
-                        connection_id: id,
-                        connection: conn,
-                    }
-                })
-        })
+            (id, conn, dispatcher, excluded_keys)
+        },
+    )
 }
-fn get_excluded_keys(preliminary_data_response: &GetDataResponse) -> Vec<Vec<u8>> {
-    preliminary_data_response
+fn get_response_keys(data_response: &GetDataResponse) -> Vec<Vec<u8>> {
+    data_response
         .data_set
         .iter()
         .map(|w| w.message.as_ref().expect("Couldn't unwrap message"))
@@ -218,7 +380,7 @@ fn get_excluded_keys(preliminary_data_response: &GetDataResponse) -> Vec<Vec<u8>
                 .into()
         })
         .chain(
-            preliminary_data_response
+            data_response
                 .persistable_network_payload_items
                 .iter()
                 .map(PersistableNetworkPayload::bisq_hash)
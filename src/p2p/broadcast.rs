@@ -3,10 +3,24 @@ use super::{
     peers::event::ConnectionAdded,
 };
 use crate::{bisq::payload::network_envelope, prelude::*};
-use std::collections::HashMap;
+use rand::{seq::SliceRandom, thread_rng};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    time::{Duration, Instant},
+};
+
+// Bisq only relays data it doesn't own to a subset of its peers
+const MAX_RELAY_PEERS: usize = 7;
+// Same limits bisq enforces per connection before reporting a rule violation
+const MSG_THROTTLE_PER_SEC: usize = 200;
+const MSG_THROTTLE_PER_10_SEC: usize = 1000;
+const MAX_RECENT_BROADCASTS: usize = 10_000;
 
 pub struct Broadcaster {
     connections: HashMap<ConnectionId, WeakAddr<Connection>>,
+    sent: HashMap<ConnectionId, Throttle>,
+    received: HashMap<ConnectionId, Throttle>,
+    recent: RecentBroadcasts,
 }
 impl Actor for Broadcaster {
     type Context = Context<Broadcaster>;
@@ -16,11 +30,66 @@ impl Broadcaster {
     pub fn start() -> Addr<Self> {
         Self {
             connections: HashMap::new(),
+            sent: HashMap::new(),
+            received: HashMap::new(),
+            recent: RecentBroadcasts::default(),
         }
         .start()
     }
 }
 
+#[derive(Default)]
+struct Throttle {
+    messages: VecDeque<Instant>,
+}
+impl Throttle {
+    fn allow(&mut self) -> bool {
+        self.allow_at(Instant::now())
+    }
+    fn allow_at(&mut self, now: Instant) -> bool {
+        while let Some(oldest) = self.messages.front() {
+            if now.duration_since(*oldest) < Duration::from_secs(10) {
+                break;
+            }
+            self.messages.pop_front();
+        }
+        let last_sec = self
+            .messages
+            .iter()
+            .rev()
+            .take_while(|at| now.duration_since(**at) < Duration::from_secs(1))
+            .count();
+        if last_sec >= MSG_THROTTLE_PER_SEC || self.messages.len() >= MSG_THROTTLE_PER_10_SEC {
+            return false;
+        }
+        self.messages.push_back(now);
+        true
+    }
+}
+
+#[derive(Default)]
+struct RecentBroadcasts {
+    hashes: HashSet<sha256::Hash>,
+    order: VecDeque<sha256::Hash>,
+}
+impl RecentBroadcasts {
+    fn insert(&mut self, message: &network_envelope::Message) -> bool {
+        let mut encoded = Vec::with_capacity(message.encoded_len());
+        message.encode(&mut encoded);
+        let hash = sha256::Hash::hash(&encoded);
+        if !self.hashes.insert(hash) {
+            return false;
+        }
+        self.order.push_back(hash);
+        if self.order.len() > MAX_RECENT_BROADCASTS {
+            if let Some(oldest) = self.order.pop_front() {
+                self.hashes.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
 pub struct Broadcast<M: Into<network_envelope::Message>>(pub M, pub Option<ConnectionId>);
 impl<M> Message for Broadcast<M>
 where
@@ -33,27 +102,53 @@ where
     M: Into<network_envelope::Message> + Send + Clone,
 {
     type Result = ();
-    fn handle(&mut self, Broadcast(message, exclude): Broadcast<M>, _ctx: &mut Self::Context) {
-        self.connections.retain(|id, conn| {
-            conn.upgrade()
-                .map(|conn| match exclude {
-                    Some(exclude) if id == &exclude => (),
-                    _ => arbiter_spawn!(conn.send(Payload(message.clone()))),
-                })
-                .is_some()
-        });
+    fn handle(&mut self, Broadcast(message, origin): Broadcast<M>, _ctx: &mut Self::Context) {
+        let message: network_envelope::Message = message.into();
+        if let Some(origin) = origin {
+            if !self.received.entry(origin).or_default().allow() {
+                warn!("{:?} exceeded the relay limit, dropping message", origin);
+                return;
+            }
+        }
+        // Only remember messages we actually relay, otherwise a throttled peer
+        // could keep a message from being relayed by anyone else
+        if !self.recent.insert(&message) {
+            return;
+        }
+
+        self.connections.retain(|_, conn| conn.upgrade().is_some());
+        let connections = &self.connections;
+        self.sent.retain(|id, _| connections.contains_key(id));
+        self.received.retain(|id, _| connections.contains_key(id));
+
+        let mut targets: Vec<ConnectionId> = self
+            .connections
+            .keys()
+            .filter(|id| Some(**id) != origin)
+            .cloned()
+            .collect();
+        if origin.is_some() {
+            targets.shuffle(&mut thread_rng());
+            targets.truncate(MAX_RELAY_PEERS);
+        }
+        for id in targets {
+            if !self.sent.entry(id).or_default().allow() {
+                debug!("Skipping broadcast to throttled {:?}", id);
+                continue;
+            }
+            if let Some(conn) = self.connections.get(&id).and_then(WeakAddr::upgrade) {
+                arbiter_spawn!(conn.send(Payload(message.clone())));
+            }
+        }
     }
 }
-#[cfg(feature = "dummy-seed")]
 pub struct Direct<M: Into<network_envelope::Message>>(pub M, pub ConnectionId);
-#[cfg(feature = "dummy-seed")]
 impl<M> Message for Direct<M>
 where
     M: Into<network_envelope::Message>,
 {
     type Result = ();
 }
-#[cfg(feature = "dummy-seed")]
 impl<M: 'static> Handler<Direct<M>> for Broadcaster
 where
     M: Into<network_envelope::Message> + Send + Clone,
@@ -79,3 +174,53 @@ impl Handler<ConnectionAdded> for Broadcaster {
         self.connections.insert(id, conn);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bisq::payload::Ping;
+
+    #[test]
+    fn throttle_limits() {
+        let start = Instant::now();
+        let mut throttle = Throttle::default();
+        assert!((0..MSG_THROTTLE_PER_SEC).all(|_| throttle.allow_at(start)));
+        assert!(!throttle.allow_at(start));
+
+        // The per second limit frees up again while the 10 second window fills
+        let mut now = start;
+        while throttle.messages.len() < MSG_THROTTLE_PER_10_SEC {
+            now += Duration::from_secs(1);
+            for _ in 0..MSG_THROTTLE_PER_SEC {
+                if throttle.messages.len() == MSG_THROTTLE_PER_10_SEC {
+                    break;
+                }
+                assert!(throttle.allow_at(now));
+            }
+        }
+        now += Duration::from_secs(1);
+        assert!(!throttle.allow_at(now));
+        assert!(throttle.allow_at(start + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn recent_broadcasts() {
+        let ping = |nonce| {
+            network_envelope::Message::Ping(Ping {
+                nonce,
+                last_round_trip_time: 0,
+            })
+        };
+        let mut recent = RecentBroadcasts::default();
+        assert!(recent.insert(&ping(0)));
+        assert!(!recent.insert(&ping(0)));
+        assert!(recent.insert(&ping(1)));
+
+        (2..=MAX_RECENT_BROADCASTS as i32).for_each(|nonce| {
+            recent.insert(&ping(nonce));
+        });
+        assert_eq!(recent.hashes.len(), MAX_RECENT_BROADCASTS);
+        assert!(recent.insert(&ping(0)));
+        assert!(!recent.insert(&ping(2)));
+    }
+}
@@ -1,8 +1,16 @@
 mod message_stream;
+mod outbound;
 
-use super::dispatch::{Dispatch, Dispatcher, SendableDispatcher};
+use super::{
+    dispatch::{Dispatch, Dispatcher, SendableDispatcher},
+    recorder,
+};
 use crate::{
-    bisq::{constants::CloseConnectionReason, correlation::*, payload::*},
+    bisq::{
+        constants::{Capability, CloseConnectionReason},
+        correlation::*,
+        payload::*,
+    },
     error,
     prelude::{
         future::Either,
@@ -14,9 +22,10 @@ use crate::{
     },
 };
 use message_stream::MessageStream;
+use outbound::{Flushed, OutboundQueue, Push, BUNDLE_DELAY};
 use prost::{encoding::encoded_len_varint, Message};
 use socks::Socks5Stream;
-use std::{collections::HashMap, net::ToSocketAddrs, thread};
+use std::{collections::HashMap, net::ToSocketAddrs, str::FromStr, thread, time::Instant};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -31,17 +40,38 @@ impl From<ConnectionId> for String {
         id.0.to_string()
     }
 }
+impl FromStr for ConnectionId {
+    type Err = uuid::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::from_str(s).map(ConnectionId)
+    }
+}
 pub struct Connection {
     id: ConnectionId,
+    message_version: MessageVersion,
+    // Bounded so a slow peer pushes back on its senders instead of growing memory
     writer: mpsc::Sender<network_envelope::Message>,
     dispatcher: Box<dyn Dispatcher>,
     response_channels: HashMap<CorrelationId, oneshot::Sender<network_envelope::Message>>,
+    outbound: OutboundQueue<oneshot::Sender<()>>,
 }
 impl Actor for Connection {
     type Context = Context<Connection>;
 }
 impl StreamHandler<network_envelope::Message, error::Error> for Connection {
     fn handle(&mut self, msg: network_envelope::Message, _ctx: &mut Self::Context) {
+        if recorder::is_recording() {
+            let envelope = NetworkEnvelope {
+                message_version: self.message_version.into(),
+                message: Some(msg.clone()),
+            };
+            recorder::record(self.id, true, &envelope);
+        }
+        if let Some(capabilities) = supported_capabilities(&msg) {
+            self.outbound.set_supports_bundles(
+                capabilities.contains(&(Capability::BundleOfEnvelopes as i32)),
+            );
+        }
         if let Some(id) = Option::<CorrelationId>::from(&msg) {
             if let Some(channel) = self.response_channels.remove(&id) {
                 channel.send(msg).expect("Couldn't send response");
@@ -107,9 +137,9 @@ impl Connection {
         let (reader, writer) = connection.split();
         let (send, rec) = mpsc::channel(10);
         let id = ConnectionId::new();
-        arbiter_spawn!(future::loop_fn((rec, writer), move |(rec, writer)| {
+        let write_loop = future::loop_fn((rec, writer), move |(rec, writer)| {
             rec.into_future()
-                .map_err(|(e, _)| e.into())
+                .map_err(|_| error::Error::ReceiveMPSCError)
                 .and_then(|(msg, rec)| {
                     msg.ok_or(error::Error::ReceiveMPSCError)
                         .map(|msg| (msg, rec))
@@ -120,6 +150,9 @@ impl Connection {
                         message_version: message_version.into(),
                         message: Some(msg),
                     };
+                    if recorder::is_recording() {
+                        recorder::record(id, false, &envelope);
+                    }
                     let len = envelope.encoded_len();
                     let required = len + encoded_len_varint(len as u64);
                     let mut serialized = Vec::with_capacity(required);
@@ -133,17 +166,21 @@ impl Connection {
                             Err(e) => Ok(Loop::Break(e)),
                         })
                 })
-                .map_err(|_| ())
-        }));
+        });
+        arbiter_spawn!(write_loop
+            .map(move |e: io::Error| warn!("{:?} couldn't write: {:?}", id, e))
+            .map_err(|_| ()));
         (
             id,
             Connection::create(move |ctx| {
                 ctx.add_stream(MessageStream::new(reader));
                 Connection {
                     id,
+                    message_version,
                     writer: send,
                     dispatcher: Box::new(dispatcher),
                     response_channels: HashMap::new(),
+                    outbound: OutboundQueue::new(message_version),
                 }
             }),
         )
@@ -173,14 +210,60 @@ where
     M: Into<network_envelope::Message>,
 {
     type Result = Box<dyn Future<Item = (), Error = error::Error>>;
-    fn handle(&mut self, Payload(msg): Payload<M>, _ctx: &mut Self::Context) -> Self::Result {
-        Box::new(
-            self.writer
-                .clone()
-                .sink_from_err::<error::Error>()
-                .send(msg.into())
-                .map(|_| ()),
-        )
+    fn handle(&mut self, Payload(msg): Payload<M>, ctx: &mut Self::Context) -> Self::Result {
+        let was_empty = self.outbound.is_empty();
+        let (waiter, written) = oneshot::channel();
+        match self.outbound.push(msg.into(), waiter, Instant::now()) {
+            Push::Send(msg) => Box::new(future::result(self.send(msg))),
+            Push::Queued(full) => {
+                if let Some(full) = full {
+                    self.write_flushed(full);
+                }
+                if was_empty {
+                    ctx.run_later(BUNDLE_DELAY, |conn, _ctx| conn.flush_outbound());
+                }
+                Box::new(written.map_err(error::Error::from))
+            }
+        }
+    }
+}
+impl Connection {
+    fn send(&mut self, msg: network_envelope::Message) -> Result<(), error::Error> {
+        self.writer.try_send(msg).map_err(|e| {
+            if e.is_full() {
+                error::Error::MPSCFull
+            } else {
+                error::Error::SendMPSCError
+            }
+        })
+    }
+
+    // Everything sent afterwards has to go out behind the queued messages
+    fn flush_outbound(&mut self) {
+        if let Some(flushed) = self.outbound.take() {
+            self.write_flushed(flushed);
+        }
+    }
+
+    // Dropping the waiters fails the payloads that didn't make it
+    fn write_flushed(&mut self, Flushed { messages, waiters }: Flushed<oneshot::Sender<()>>) {
+        match messages.into_iter().try_for_each(|msg| self.send(msg)) {
+            Ok(()) => waiters.into_iter().for_each(|waiter| {
+                let _ = waiter.send(());
+            }),
+            Err(e) => warn!("{:?} couldn't send queued messages: {:?}", self.id, e),
+        }
+    }
+}
+fn supported_capabilities(msg: &network_envelope::Message) -> Option<&Vec<i32>> {
+    match msg {
+        network_envelope::Message::PreliminaryGetDataRequest(msg) => {
+            Some(&msg.supported_capabilities)
+        }
+        network_envelope::Message::GetDataResponse(msg) => Some(&msg.supported_capabilities),
+        network_envelope::Message::GetPeersRequest(msg) => Some(&msg.supported_capabilities),
+        network_envelope::Message::GetPeersResponse(msg) => Some(&msg.supported_capabilities),
+        _ => None,
     }
 }
 pub struct Request<M: Into<network_envelope::Message> + ResponseExtractor>(pub M);
@@ -201,17 +284,12 @@ where
             Option::<CorrelationId>::from(&msg).expect("Request without correlation_id");
         let (send, receive) = oneshot::channel::<network_envelope::Message>();
         self.response_channels.insert(correlation_id, send);
-        Box::new(
-            self.writer
-                .clone()
-                .sink_from_err::<error::Error>()
-                .send(msg)
-                .and_then(|_| {
-                    receive
-                        .map(<M as ResponseExtractor>::extract)
-                        .map_err(|e| e.into())
-                }),
-        )
+        self.flush_outbound();
+        Box::new(future::result(self.send(msg)).and_then(|_| {
+            receive
+                .map(<M as ResponseExtractor>::extract)
+                .map_err(|e| e.into())
+        }))
     }
 }
 pub struct Shutdown(pub CloseConnectionReason);
@@ -221,20 +299,11 @@ impl actix::Message for Shutdown {
 impl Handler<Shutdown> for Connection {
     type Result = ();
     fn handle(&mut self, Shutdown(reason): Shutdown, ctx: &mut Self::Context) {
+        self.flush_outbound();
         let reason: String = reason.into();
         info!("Shutting down {:?} because {}", self.id, reason);
-        ctx.spawn(
-            fut::wrap_future(
-                self.writer
-                    .clone()
-                    .sink_from_err::<error::Error>()
-                    .send(CloseConnectionMessage { reason }.into())
-                    .then(|_| Ok(())),
-            )
-            .then(|_: Result<(), ()>, _, ctx: &mut Self::Context| {
-                ctx.stop();
-                fut::ok(())
-            }),
-        );
+        // The writer still drains the channel once the connection is gone
+        let _ = self.send(CloseConnectionMessage { reason }.into());
+        ctx.stop();
     }
 }
@@ -0,0 +1,212 @@
+use crate::bisq::{
+    constants::MAX_PERMITTED_MESSAGE_SIZE,
+    payload::{network_envelope, BundleOfEnvelopes, MessageVersion, NetworkEnvelope},
+};
+use std::{
+    mem,
+    time::{Duration, Instant},
+};
+
+// Same values bisq uses to decide when to bundle outgoing messages
+const BUNDLE_TRIGGER: Duration = Duration::from_millis(20);
+pub const BUNDLE_DELAY: Duration = Duration::from_millis(50);
+const MAX_BUNDLE_SIZE: usize = MAX_PERMITTED_MESSAGE_SIZE / 2;
+
+pub enum Push<W> {
+    // Nothing is queued, so the message can be written right away
+    Send(network_envelope::Message),
+    // The message got queued. A full queue has to be written first.
+    Queued(Option<Flushed<W>>),
+}
+
+// The messages to write in order, the waiters belong to the queued payloads
+pub struct Flushed<W> {
+    pub messages: Vec<network_envelope::Message>,
+    pub waiters: Vec<W>,
+}
+
+// Collects bursts of outbound messages so they can go out as a single BundleOfEnvelopes
+pub struct OutboundQueue<W> {
+    message_version: MessageVersion,
+    supports_bundles: bool,
+    last_push: Option<Instant>,
+    messages: Vec<network_envelope::Message>,
+    waiters: Vec<W>,
+    size: usize,
+}
+impl<W> OutboundQueue<W> {
+    pub fn new(message_version: MessageVersion) -> Self {
+        Self {
+            message_version,
+            supports_bundles: false,
+            last_push: None,
+            messages: Vec::new(),
+            waiters: Vec::new(),
+            size: 0,
+        }
+    }
+
+    pub fn set_supports_bundles(&mut self, supports_bundles: bool) {
+        self.supports_bundles = supports_bundles;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    pub fn push(&mut self, msg: network_envelope::Message, waiter: W, now: Instant) -> Push<W> {
+        let in_burst = self
+            .last_push
+            .map(|last_push| now.duration_since(last_push) < BUNDLE_TRIGGER)
+            .unwrap_or(false);
+        self.last_push = Some(now);
+        if self.is_empty() && (!self.supports_bundles || !in_burst) {
+            return Push::Send(msg);
+        }
+
+        let size = msg.encoded_len();
+        let flushed = if self.size + size > MAX_BUNDLE_SIZE {
+            self.take()
+        } else {
+            None
+        };
+        self.size += size;
+        self.messages.push(msg);
+        self.waiters.push(waiter);
+        Push::Queued(flushed)
+    }
+
+    // Peers that stopped announcing bundle support get the messages one by one
+    pub fn take(&mut self) -> Option<Flushed<W>> {
+        if self.is_empty() {
+            return None;
+        }
+        self.size = 0;
+        let mut messages = mem::take(&mut self.messages);
+        let waiters = mem::take(&mut self.waiters);
+        if self.supports_bundles && messages.len() > 1 {
+            let message_version = self.message_version;
+            messages = vec![BundleOfEnvelopes {
+                envelopes: messages
+                    .into_iter()
+                    .map(|msg| NetworkEnvelope {
+                        message_version: message_version.into(),
+                        message: Some(msg),
+                    })
+                    .collect(),
+            }
+            .into()];
+        }
+        Some(Flushed { messages, waiters })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bisq::{
+        constants::BaseCurrencyNetwork,
+        payload::{CloseConnectionMessage, Ping},
+    };
+
+    fn queue(supports_bundles: bool) -> OutboundQueue<i32> {
+        let mut queue = OutboundQueue::new(BaseCurrencyNetwork::BtcRegtest.into());
+        queue.set_supports_bundles(supports_bundles);
+        queue
+    }
+    fn ping(nonce: i32) -> network_envelope::Message {
+        Ping {
+            nonce,
+            last_round_trip_time: 0,
+        }
+        .into()
+    }
+    fn bundled(msg: &network_envelope::Message) -> Vec<network_envelope::Message> {
+        match msg {
+            network_envelope::Message::BundleOfEnvelopes(bundle) => bundle
+                .envelopes
+                .iter()
+                .filter_map(|envelope| envelope.message.clone())
+                .collect(),
+            _ => panic!("Not a bundle"),
+        }
+    }
+
+    #[test]
+    fn bundles_bursts() {
+        let start = Instant::now();
+        let mut queue = queue(true);
+        assert!(match queue.push(ping(0), 0, start) {
+            Push::Send(msg) => msg == ping(0),
+            _ => false,
+        });
+        for nonce in 1..4 {
+            let now = start + Duration::from_millis(nonce as u64);
+            assert!(match queue.push(ping(nonce), nonce, now) {
+                Push::Queued(None) => true,
+                _ => false,
+            });
+        }
+        let flushed = queue.take().expect("Queue is empty");
+        assert_eq!(flushed.waiters, vec![1, 2, 3]);
+        assert_eq!(flushed.messages.len(), 1);
+        assert_eq!(
+            bundled(&flushed.messages[0]),
+            vec![ping(1), ping(2), ping(3)]
+        );
+        assert!(queue.take().is_none());
+
+        // Once a bundle is pending everything gets queued behind it
+        let later = start + BUNDLE_TRIGGER * 5;
+        queue.push(ping(4), 4, later);
+        queue.push(ping(5), 5, later);
+        assert!(match queue.push(ping(6), 6, later + BUNDLE_TRIGGER * 5) {
+            Push::Queued(None) => true,
+            _ => false,
+        });
+        assert_eq!(queue.take().expect("Queue is empty").waiters, vec![5, 6]);
+    }
+
+    #[test]
+    fn caps_bundle_size() {
+        let start = Instant::now();
+        let mut queue = queue(true);
+        let large = |nonce: usize| -> network_envelope::Message {
+            CloseConnectionMessage {
+                reason: nonce.to_string().repeat(MAX_BUNDLE_SIZE / 3),
+            }
+            .into()
+        };
+        queue.push(ping(0), 0, start);
+        let mut flushed = None;
+        for nonce in 1..=3 {
+            if let Push::Queued(full) = queue.push(large(nonce), nonce as i32, start) {
+                flushed = flushed.or(full);
+            }
+        }
+        let flushed = flushed.expect("Bundle wasn't capped");
+        assert_eq!(flushed.waiters, vec![1, 2]);
+        assert_eq!(bundled(&flushed.messages[0]), vec![large(1), large(2)]);
+        assert_eq!(queue.take().expect("Queue is empty").waiters, vec![3]);
+    }
+
+    #[test]
+    fn falls_back_to_single_envelopes() {
+        let start = Instant::now();
+        let mut queue = queue(false);
+        for nonce in 0..3 {
+            assert!(match queue.push(ping(nonce), nonce, start) {
+                Push::Send(msg) => msg == ping(nonce),
+                _ => false,
+            });
+        }
+        assert!(queue.take().is_none());
+
+        queue.set_supports_bundles(true);
+        queue.push(ping(3), 3, start);
+        queue.push(ping(4), 4, start);
+        queue.set_supports_bundles(false);
+        let flushed = queue.take().expect("Queue is empty");
+        assert_eq!(flushed.messages, vec![ping(3), ping(4)]);
+    }
+}
@@ -6,6 +6,7 @@ mod status;
 mod tor;
 
 pub mod dispatch;
+pub mod recorder;
 pub mod server;
 
 pub use bootstrap::{Bootstrap, BootstrapState};
@@ -14,9 +15,8 @@ pub use connection::{Connection, ConnectionId, Request};
 pub use peers::Peers;
 pub use server::TorConfig;
 pub use status::*;
+pub use tor::{TorProcess, TorProcessConfig};
 
 pub mod message {
-    pub use super::broadcast::Broadcast;
-    #[cfg(feature = "dummy-seed")]
-    pub use super::broadcast::Direct;
+    pub use super::broadcast::{Broadcast, Direct};
 }
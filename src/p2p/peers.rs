@@ -10,22 +10,31 @@ use super::{
 use crate::{
     bisq::{
         constants::{
-            self, BaseCurrencyNetwork, Capability, CloseConnectionReason, LOCAL_CAPABILITIES,
+            BaseCurrencyNetwork, Capability, CloseConnectionReason, LOCAL_CAPABILITIES,
+            SEED_NODE_CAPABILITIES,
         },
-        payload::*,
+        payload::{persistable_envelope, *},
+        persistence,
     },
+    domain::filter::FilterCache,
     prelude::{fut::Either, *},
 };
 use keep_alive::*;
 use std::{
     collections::{HashMap, HashSet},
     convert::TryInto,
+    path::PathBuf,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 const CONSOLIDATE_CONNECTIONS: Duration = Duration::from_secs(60);
 const MAX_CONNECTIONS: usize = 12;
 const MIN_CONNECTIONS: usize = MAX_CONNECTIONS / 7 * 10;
+const SEED_NODE_MAX_CONNECTIONS: usize = 30;
+const SEED_NODE_MIN_CONNECTIONS: usize = 20;
+const MAX_REPORTED_PEERS: usize = 1000;
+const MAX_PERSISTED_PEERS: usize = 500;
+const MAX_PERSISTED_PEER_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60);
 
 struct PeerInfo {
     reported_alive_at: SystemTime,
@@ -62,6 +71,11 @@ pub struct Peers<D: SendableDispatcher> {
     dispatcher: D,
     proxy_port: Option<u16>,
     status: Status,
+    filter_cache: FilterCache,
+    peer_list_path: Option<PathBuf>,
+    seed_node: bool,
+    max_connections: usize,
+    min_connections: usize,
 }
 
 impl<D: SendableDispatcher> Peers<D> {
@@ -69,10 +83,18 @@ impl<D: SendableDispatcher> Peers<D> {
         network: BaseCurrencyNetwork,
         broadcaster: Addr<Broadcaster>,
         status: Status,
+        filter_cache: FilterCache,
         dispatcher: D,
         proxy_port: Option<u16>,
+        peer_list_path: Option<PathBuf>,
+        seed_node: bool,
     ) -> Addr<Self> {
-        Self {
+        let (max_connections, min_connections) = if seed_node {
+            (SEED_NODE_MAX_CONNECTIONS, SEED_NODE_MIN_CONNECTIONS)
+        } else {
+            (MAX_CONNECTIONS, MIN_CONNECTIONS)
+        };
+        let mut peers = Self {
             keep_alive: KeepAlive::start(),
             broadcaster,
             network,
@@ -83,8 +105,57 @@ impl<D: SendableDispatcher> Peers<D> {
             dispatcher,
             proxy_port,
             status,
+            filter_cache,
+            peer_list_path,
+            seed_node,
+            max_connections,
+            min_connections,
+        };
+        peers.load_peer_list();
+        peers.start()
+    }
+
+    fn load_peer_list(&mut self) {
+        let path = match self.peer_list_path.as_ref() {
+            Some(path) => path,
+            None => return,
+        };
+        match persistence::read(path) {
+            Ok(Some(persistable_envelope::Message::PeerList(PeerList { peer }))) => {
+                info!("Loaded {} persisted peers", peer.len());
+                self.add_to_peer_infos(peer)
+            }
+            Ok(None) => (),
+            Ok(Some(_)) => warn!("Unexpected content in {}", path.display()),
+            Err(e) => warn!("Couldn't load peer list: {:?}", e),
+        }
+    }
+
+    fn persist_peer_list(&self) {
+        let path = match self.peer_list_path.as_ref() {
+            Some(path) => path,
+            None => return,
+        };
+        let now = SystemTime::now();
+        let mut peer: Vec<Peer> = self
+            .peer_infos
+            .iter()
+            .filter(|(_, info)| {
+                now.duration_since(info.reported_alive_at)
+                    .map(|age| age < MAX_PERSISTED_PEER_AGE)
+                    .unwrap_or(true)
+            })
+            .map(|(addr, info)| (addr.clone(), info).into())
+            .collect();
+        peer.sort_by(|a, b| b.date.cmp(&a.date));
+        peer.truncate(MAX_PERSISTED_PEERS);
+        debug!("Persisting {} peers", peer.len());
+        if let Err(e) = persistence::write(
+            path,
+            persistable_envelope::Message::PeerList(PeerList { peer }),
+        ) {
+            error!("Couldn't persist peer list: {:?}", e);
         }
-        .start()
     }
 
     fn get_dispatcher(&self, addr: Addr<Peers<D>>) -> impl SendableDispatcher {
@@ -154,6 +225,7 @@ impl<D: SendableDispatcher> Peers<D> {
 
     fn consolidate_connections(&mut self, ctx: &mut <Self as Actor>::Context) {
         info!("Consolidating peer connections");
+        self.drop_banned_connections();
         let remove_ids: Vec<ConnectionId> = self
             .connections
             .iter()
@@ -176,8 +248,9 @@ impl<D: SendableDispatcher> Peers<D> {
         });
 
         ctx.spawn(self.update_alive_times().then(|_, peers, _ctx| {
+            peers.persist_peer_list();
             let candidates = peers.new_connection_candidates();
-            if candidates.len() + peers.identified_connections.len() < MIN_CONNECTIONS * 2 {
+            if candidates.len() + peers.identified_connections.len() < peers.min_connections * 2 {
                 Either::A(peers.request_peers())
             } else {
                 Either::B(fut::ok(()))
@@ -197,19 +270,38 @@ impl<D: SendableDispatcher> Peers<D> {
             }
         }
     }
+    fn drop_banned_connections(&mut self) {
+        let banned: Vec<ConnectionId> = self
+            .identified_connections
+            .iter()
+            .filter(|(_, addr)| self.filter_cache.is_node_banned(addr))
+            .map(|(id, _)| *id)
+            .collect();
+        banned.into_iter().for_each(|id| {
+            info!("Dropping connection to banned node {:?}", id);
+            self.drop_connection(&id, CloseConnectionReason::PeerBanned)
+        });
+    }
     fn new_connection_candidates(&self) -> HashSet<&NodeAddress> {
-        let mut candidates: HashSet<&NodeAddress> = self.peer_infos.keys().collect();
+        let mut candidates: HashSet<&NodeAddress> = self
+            .peer_infos
+            .keys()
+            .filter(|addr| !self.filter_cache.is_node_banned(addr))
+            .collect();
         self.identified_connections.values().for_each(|v| {
             candidates.remove(&v);
         });
+        if let Some(local_addr) = self.local_addr.as_ref() {
+            candidates.remove(local_addr);
+        }
         candidates
     }
 
     fn do_consolidate_connections(&mut self, ctx: &mut <Self as Actor>::Context) {
-        if self.identified_connections.len() < MIN_CONNECTIONS {
+        if self.identified_connections.len() < self.min_connections {
             self.new_connection_candidates()
                 .into_iter()
-                .take(MAX_CONNECTIONS - self.connections.len())
+                .take(self.max_connections.saturating_sub(self.connections.len()))
                 .cloned()
                 .for_each(|addr| {
                     ctx.spawn(
@@ -228,11 +320,11 @@ impl<D: SendableDispatcher> Peers<D> {
                         }),
                     );
                 });
-        } else if self.connections.len() > MAX_CONNECTIONS {
+        } else if self.connections.len() > self.max_connections {
             let to_drop: Vec<ConnectionId> = self
                 .connections
                 .keys()
-                .take(self.connections.len() - MAX_CONNECTIONS)
+                .take(self.connections.len() - self.max_connections)
                 .cloned()
                 .collect();
             to_drop.into_iter().for_each(|id| {
@@ -256,7 +348,7 @@ impl<D: SendableDispatcher> Peers<D> {
             let request = GetPeersRequest {
                 sender_node_address: self.local_addr.clone(),
                 nonce: gen_nonce(),
-                supported_capabilities: LOCAL_CAPABILITIES.clone(),
+                supported_capabilities: self.local_capabilities(),
                 reported_peers: self.peers_to_report(&id),
             };
             Either::A(
@@ -269,6 +361,9 @@ impl<D: SendableDispatcher> Peers<D> {
                               },
                               peers: &mut Peers<D>,
                               _ctx| {
+                            peers
+                                .status
+                                .connection_capabilities(&id, supported_capabilities.clone());
                             if let Some(ref addr) = peers
                                 .identified_connections
                                 .get(&id)
@@ -291,6 +386,28 @@ impl<D: SendableDispatcher> Peers<D> {
         }
     }
 
+    fn local_capabilities(&self) -> Vec<i32> {
+        if self.seed_node {
+            SEED_NODE_CAPABILITIES.clone()
+        } else {
+            LOCAL_CAPABILITIES.clone()
+        }
+    }
+
+    // Seed nodes are the first contact for new nodes so they share everything they know
+    fn seed_peers_to_report(&self, exclude: &ConnectionId) -> Vec<Peer> {
+        let excluded_addr = self.identified_connections.get(exclude);
+        let mut peers: Vec<Peer> = self
+            .peer_infos
+            .iter()
+            .filter(|(addr, _)| Some(*addr) != excluded_addr)
+            .map(|(addr, info)| (addr.clone(), info).into())
+            .collect();
+        peers.sort_by(|a, b| b.date.cmp(&a.date));
+        peers.truncate(MAX_REPORTED_PEERS);
+        peers
+    }
+
     fn peers_to_report(&self, exclude: &ConnectionId) -> Vec<Peer> {
         self.identified_connections
             .iter()
@@ -313,7 +430,10 @@ impl<D: SendableDispatcher> Peers<D> {
                  date,
                  supported_capabilities,
              }| {
-                if let Some(addr) = node_address {
+                if let Some(addr) = node_address
+                    .filter(NodeAddress::is_valid)
+                    .filter(|addr| !self.filter_cache.is_node_banned(addr))
+                {
                     self.update_peer_info(
                         &addr,
                         UNIX_EPOCH + Duration::from_millis(date as u64),
@@ -384,7 +504,17 @@ impl<D: SendableDispatcher> Handler<Receive<GetPeersRequest>> for Peers<D> {
         ): Receive<GetPeersRequest>,
         ctx: &mut Self::Context,
     ) -> Self::Result {
+        if let Some(addr) = sender_node_address
+            .as_ref()
+            .filter(|addr| self.filter_cache.is_node_banned(addr))
+        {
+            info!("Refusing banned node {}", addr);
+            self.drop_connection(&conn_id, CloseConnectionReason::PeerBanned);
+            return;
+        }
         self.add_to_peer_infos(reported_peers);
+        self.status
+            .connection_capabilities(&conn_id, supported_capabilities.clone());
         if let Some(addr) = sender_node_address {
             self.update_peer_info(&addr, SystemTime::now(), None, Some(supported_capabilities));
             self.status.connection_identified(&conn_id, &addr);
@@ -392,10 +522,15 @@ impl<D: SendableDispatcher> Handler<Receive<GetPeersRequest>> for Peers<D> {
         }
         if let Some(conn) = self.connections.get(&conn_id).map(Addr::clone) {
             ctx.spawn(self.update_alive_times().then(move |_, peers, _| {
+                let reported_peers = if peers.seed_node {
+                    peers.seed_peers_to_report(&conn_id)
+                } else {
+                    peers.peers_to_report(&conn_id)
+                };
                 let res = GetPeersResponse {
                     request_nonce: nonce,
-                    reported_peers: peers.peers_to_report(&conn_id),
-                    supported_capabilities: constants::LOCAL_CAPABILITIES.clone(),
+                    reported_peers,
+                    supported_capabilities: peers.local_capabilities(),
                 };
                 fut::wrap_future(conn.send(Payload(res)).then(|_| Ok(())))
             }));
@@ -0,0 +1,203 @@
+use super::connection::ConnectionId;
+use crate::{bisq::payload::*, error::Error};
+use lazy_static::lazy_static;
+use prost::{encoding::decode_varint, Message};
+use std::{
+    ffi::OsString,
+    fs::{self, File},
+    io::{self, BufWriter, Cursor, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const INDEX_EXTENSION: &str = ".idx";
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+lazy_static! {
+    static ref RECORDING: Mutex<Option<Recording>> = Mutex::new(None);
+}
+static IS_RECORDING: AtomicBool = AtomicBool::new(false);
+
+struct Recording {
+    records: mpsc::Sender<(RecordIndex, NetworkEnvelope)>,
+    writer: JoinHandle<()>,
+}
+
+// The recording itself only holds length delimited NetworkEnvelopes, exactly
+// as they are framed on the wire, so MessageStream can parse it.
+// The sidecar index holds one length delimited RecordIndex per envelope.
+#[derive(Clone, PartialEq, Message)]
+struct RecordIndex {
+    #[prost(int64, tag = "1")]
+    timestamp: i64,
+    #[prost(string, tag = "2")]
+    connection_id: String,
+    #[prost(bool, tag = "3")]
+    inbound: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RecordedEnvelope {
+    pub timestamp: i64,
+    pub connection_id: String,
+    pub inbound: bool,
+    pub envelope: NetworkEnvelope,
+}
+
+fn index_path(path: &Path) -> PathBuf {
+    let mut index = OsString::from(path.as_os_str());
+    index.push(INDEX_EXTENSION);
+    index.into()
+}
+
+pub fn start_recording(path: &Path) -> io::Result<()> {
+    let mut envelopes = BufWriter::new(File::create(path)?);
+    let mut index = BufWriter::new(File::create(index_path(path))?);
+    let (records, receiver) = mpsc::channel::<(RecordIndex, NetworkEnvelope)>();
+    // Writing happens off the connections so recording doesn't stall them
+    let writer = thread::spawn(move || loop {
+        let result = match receiver.recv_timeout(FLUSH_INTERVAL) {
+            Ok((entry, envelope)) => write_delimited(&mut index, &entry)
+                .and_then(|_| write_delimited(&mut envelopes, &envelope)),
+            Err(RecvTimeoutError::Timeout) => envelopes.flush().and_then(|_| index.flush()),
+            Err(RecvTimeoutError::Disconnected) => {
+                let _ = envelopes.flush().and_then(|_| index.flush());
+                return;
+            }
+        };
+        if let Err(e) = result {
+            error!("Couldn't write recording, stopping: {:?}", e);
+            IS_RECORDING.store(false, Ordering::SeqCst);
+            return;
+        }
+    });
+    *RECORDING.lock().expect("Recording lock poisoned") = Some(Recording { records, writer });
+    IS_RECORDING.store(true, Ordering::SeqCst);
+    info!("Recording p2p traffic to {}", path.display());
+    Ok(())
+}
+
+// Waits until everything recorded so far has been written
+pub fn stop_recording() {
+    IS_RECORDING.store(false, Ordering::SeqCst);
+    let recording = RECORDING.lock().expect("Recording lock poisoned").take();
+    if let Some(Recording { records, writer }) = recording {
+        drop(records);
+        let _ = writer.join();
+    }
+}
+
+pub fn is_recording() -> bool {
+    IS_RECORDING.load(Ordering::Relaxed)
+}
+
+pub fn record(conn: ConnectionId, inbound: bool, envelope: &NetworkEnvelope) {
+    let entry = RecordIndex {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as i64,
+        connection_id: conn.into(),
+        inbound,
+    };
+    let recording = RECORDING.lock().expect("Recording lock poisoned");
+    if let Some(recording) = recording.as_ref() {
+        let _ = recording.records.send((entry, envelope.clone()));
+    }
+}
+
+pub fn read_recording(path: &Path) -> Result<Vec<RecordedEnvelope>, Error> {
+    let envelopes = read_delimited::<NetworkEnvelope>(path)?;
+    let index = read_delimited::<RecordIndex>(&index_path(path))?;
+    if envelopes.len() != index.len() {
+        warn!(
+            "Recording has {} envelopes but {} index entries",
+            envelopes.len(),
+            index.len()
+        );
+    }
+    Ok(index
+        .into_iter()
+        .zip(envelopes)
+        .map(|(entry, envelope)| RecordedEnvelope {
+            timestamp: entry.timestamp,
+            connection_id: entry.connection_id,
+            inbound: entry.inbound,
+            envelope,
+        })
+        .collect())
+}
+
+fn write_delimited<M: Message>(writer: &mut impl Write, msg: &M) -> io::Result<()> {
+    let mut serialized = Vec::new();
+    msg.encode_length_delimited(&mut serialized)
+        .expect("Could not encode record");
+    writer.write_all(&serialized)
+}
+
+fn read_delimited<M: Message + Default>(path: &Path) -> Result<Vec<M>, Error> {
+    let data = fs::read(path)?;
+    let total = data.len();
+    let mut buf = Cursor::new(data);
+    let mut messages = Vec::new();
+    while (buf.position() as usize) < total {
+        let len = decode_varint(&mut buf)? as usize;
+        let start = buf.position() as usize;
+        if start + len > total {
+            warn!("{} ends with a truncated record", path.display());
+            break;
+        }
+        messages.push(M::decode(&buf.get_ref()[start..start + len])?);
+        buf.set_position((start + len) as u64);
+    }
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    #[test]
+    fn round_trip() {
+        let path = env::temp_dir().join(format!("risq-recording-{}", process::id()));
+        let conn: ConnectionId = "0a2c5f0e-3b5a-4b8e-9c52-6f7d2e1b9a30".parse().unwrap();
+        let envelope = |nonce| NetworkEnvelope {
+            message_version: 10,
+            message: Some(
+                Ping {
+                    nonce,
+                    last_round_trip_time: 0,
+                }
+                .into(),
+            ),
+        };
+        start_recording(&path).expect("Couldn't start recording");
+        record(conn, true, &envelope(1));
+        record(conn, false, &envelope(2));
+        stop_recording();
+
+        let records = read_recording(&path).expect("Couldn't read recording");
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].connection_id, String::from(conn));
+        assert!(records[0].inbound);
+        assert_eq!(records[0].envelope, envelope(1));
+        assert!(!records[1].inbound);
+        assert_eq!(records[1].envelope, envelope(2));
+        assert!(records[0].timestamp <= records[1].timestamp);
+        // The recording itself is plain wire framing
+        assert_eq!(
+            read_delimited::<NetworkEnvelope>(&path).unwrap(),
+            vec![envelope(1), envelope(2)]
+        );
+
+        let _ = fs::remove_file(index_path(&path));
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -2,7 +2,8 @@ use super::{
     bootstrap::Bootstrap,
     dispatch::SendableDispatcher,
     peers::Peers,
-    tor::{AddOnionConfig, TorControl},
+    status::Status,
+    tor::{AddOnionConfig, TorControl, TorEvent},
 };
 use crate::{
     bisq::payload::NodeAddress,
@@ -15,11 +16,18 @@ use std::{
     io,
     net::{IpAddr, Ipv4Addr, SocketAddr},
     path::PathBuf,
+    thread,
+    time::Duration,
 };
 
+const TOR_EVENTS: [&str; 3] = ["STATUS_CLIENT", "HS_DESC", "CIRC"];
+// Announce anyway if tor didn't report the descriptor upload in time
+const DESCRIPTOR_TIMEOUT: Duration = Duration::from_secs(120);
+
 pub struct TorConfig {
     pub hidden_service_port: u16,
     pub tc_port: u16,
+    pub control_password: Option<String>,
     pub private_key_path: PathBuf,
 }
 
@@ -28,11 +36,18 @@ pub struct Server<D: SendableDispatcher> {
     tor_conf: Option<TorConfig>,
     peers: Addr<Peers<D>>,
     bootstrap: Option<Addr<Bootstrap<D>>>,
+    status: Status,
+    // Tor removes the hidden service once the control connection is closed
+    tor_control: Option<TorControl>,
+    service_id: Option<String>,
+    // Only announced once the hidden service descriptor has been published
+    pending_addr: Option<NodeAddress>,
 }
 pub fn start<D: SendableDispatcher>(
     listen_port: u16,
     peers: Addr<Peers<D>>,
     bootstrap: Option<Addr<Bootstrap<D>>>,
+    status: Status,
     tor_conf: Option<TorConfig>,
 ) -> Addr<Server<D>> {
     Server {
@@ -40,6 +55,10 @@ pub fn start<D: SendableDispatcher>(
         tor_conf,
         peers,
         bootstrap,
+        status,
+        tor_control: None,
+        service_id: None,
+        pending_addr: None,
     }
     .start()
 }
@@ -50,34 +69,162 @@ impl<D: SendableDispatcher> Actor for Server<D> {
             SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), self.listen_port);
         let tcp = TcpListener::bind(&listen_socket).expect("Unable to bind port");
         ctx.add_stream(tcp.incoming());
-        let addr = match &self.tor_conf {
-            Some(tor_conf) => {
-                let mut tc = TorControl::connect(("127.0.0.1", tor_conf.tc_port))
-                    .expect("Couldn't authenticate to TorControl");
-                let onion_addr = tc
-                    .add_v2_onion(AddOnionConfig {
-                        virtual_port: tor_conf.hidden_service_port,
-                        target_port: self.listen_port,
-                        private_key_path: tor_conf.private_key_path.clone(),
-                    })
-                    .expect("Couldn't create hidden service");
-                NodeAddress {
-                    host_name: onion_addr.onion_service,
-                    port: onion_addr.port as i32,
-                }
-            }
-            None => NodeAddress {
+        if self.tor_conf.is_none() {
+            self.announce(NodeAddress {
                 host_name: "127.0.0.1".to_string(),
                 port: self.listen_port as i32,
-            },
-        };
-
+            });
+            return;
+        }
+        match self.add_hidden_service(ctx) {
+            Some((addr, true)) => {
+                info!("Waiting for hidden service descriptor of {:?}", addr);
+                self.pending_addr = Some(addr);
+                ctx.run_later(DESCRIPTOR_TIMEOUT, |server, _| {
+                    if let Some(addr) = server.pending_addr.take() {
+                        warn!("No descriptor upload reported for {:?}", addr);
+                        server.announce(addr);
+                    }
+                });
+            }
+            // Without events there is no way to learn about the upload
+            Some((addr, false)) => self.announce(addr),
+            None => System::current().stop(),
+        }
+    }
+}
+impl<D: SendableDispatcher> Server<D> {
+    fn announce(&self, addr: NodeAddress) {
         info!("Server started @ {:?}", addr);
         if let Some(bootstrap) = self.bootstrap.as_ref() {
             arbiter_spawn!(bootstrap.send(event::ServerStarted(addr.clone())))
         }
         arbiter_spawn!(self.peers.send(event::ServerStarted(addr)));
     }
+
+    // Also returns whether tor events could be subscribed to
+    fn add_hidden_service(
+        &mut self,
+        ctx: &mut <Self as Actor>::Context,
+    ) -> Option<(NodeAddress, bool)> {
+        let tor_conf = self.tor_conf.as_ref()?;
+        let connect = || {
+            TorControl::connect(
+                ("127.0.0.1", tor_conf.tc_port),
+                tor_conf.control_password.as_deref(),
+            )
+        };
+        let mut tc = match connect() {
+            Ok(tc) => tc,
+            Err(e) => {
+                error!("Couldn't authenticate to TorControl: {:?}", e);
+                return None;
+            }
+        };
+        // Subscribe before creating the service so no descriptor upload is missed
+        let subscribed = match connect().and_then(|mut events| {
+            let progress = events.bootstrap_progress()?;
+            events.set_events(&TOR_EVENTS).map(|_| (events, progress))
+        }) {
+            Ok((events, progress)) => {
+                self.status
+                    .update_tor_health(|health| health.bootstrap_progress = progress);
+                forward_events(events, ctx.address().recipient());
+                true
+            }
+            Err(e) => {
+                warn!("Couldn't subscribe to tor events: {:?}", e);
+                self.status.update_tor_health(|_| ());
+                false
+            }
+        };
+        let onion_addr = match tc.add_onion(AddOnionConfig {
+            virtual_port: tor_conf.hidden_service_port,
+            target_port: self.listen_port,
+            private_key_path: tor_conf.private_key_path.clone(),
+        }) {
+            Ok(onion_addr) => onion_addr,
+            Err(e) => {
+                error!("Couldn't create hidden service: {:?}", e);
+                return None;
+            }
+        };
+        self.tor_control = Some(tc);
+        self.service_id = Some(onion_addr.onion_service.trim_end_matches(".onion").into());
+        Some((
+            NodeAddress {
+                host_name: onion_addr.onion_service,
+                port: onion_addr.port as i32,
+            },
+            subscribed,
+        ))
+    }
+}
+fn forward_events(mut events: TorControl, recipient: Recipient<event::TorEventReceived>) {
+    thread::spawn(move || loop {
+        match events.next_event() {
+            Ok(TorEvent::Other) => (),
+            Ok(event) => {
+                if recipient.do_send(event::TorEventReceived(event)).is_err() {
+                    return;
+                }
+            }
+            Err(e) => {
+                warn!("Stopped receiving tor events: {:?}", e);
+                return;
+            }
+        }
+    });
+}
+impl<D: SendableDispatcher> Handler<event::TorEventReceived> for Server<D> {
+    type Result = ();
+    fn handle(
+        &mut self,
+        event::TorEventReceived(event): event::TorEventReceived,
+        _ctx: &mut Self::Context,
+    ) {
+        let is_own = |id: &str| self.service_id.as_ref().map(|own| own == id) == Some(true);
+        match event {
+            TorEvent::BootstrapProgress(progress) => {
+                debug!("Tor bootstrap progress: {}%", progress);
+                self.status
+                    .update_tor_health(|health| health.bootstrap_progress = progress);
+            }
+            TorEvent::DescriptorUploaded(ref id) if is_own(id) => {
+                self.status.update_tor_health(|health| {
+                    health.descriptor_published = true;
+                    health.descriptor_uploads += 1;
+                });
+                if let Some(addr) = self.pending_addr.take() {
+                    self.announce(addr);
+                }
+            }
+            TorEvent::DescriptorUploadFailed(ref id, ref reason) if is_own(id) => {
+                debug!("Hidden service descriptor upload failed: {}", reason);
+                self.status
+                    .update_tor_health(|health| health.descriptor_upload_failures += 1);
+            }
+            TorEvent::CircuitBuilt => self
+                .status
+                .update_tor_health(|health| health.circuits_built += 1),
+            TorEvent::CircuitFailed(reason) => self.status.update_tor_health(|health| {
+                health.circuits_failed += 1;
+                health.last_circuit_failure = Some(reason);
+            }),
+            _ => (),
+        }
+    }
+}
+impl<D: SendableDispatcher> Handler<event::TorRestarted> for Server<D> {
+    type Result = ();
+    fn handle(&mut self, _: event::TorRestarted, ctx: &mut Self::Context) {
+        self.status
+            .update_tor_health(|health| health.descriptor_published = false);
+        // The key is persisted so the service comes back under the same address
+        if let Some((addr, _)) = self.add_hidden_service(ctx) {
+            info!("Hidden service restored @ {:?}", addr);
+        }
+    }
 }
 impl<D: SendableDispatcher> StreamHandler<TcpStream, io::Error> for Server<D> {
     fn handle(&mut self, connection: TcpStream, _ctx: &mut Self::Context) {
@@ -86,7 +233,7 @@ impl<D: SendableDispatcher> StreamHandler<TcpStream, io::Error> for Server<D> {
 }
 
 pub mod event {
-    use crate::bisq::payload::NodeAddress;
+    use crate::{bisq::payload::NodeAddress, p2p::tor::TorEvent};
     use actix::Message;
     use tokio::net::TcpStream;
 
@@ -98,4 +245,12 @@ pub mod event {
     impl Message for IncomingConnection {
         type Result = ();
     }
+    pub struct TorRestarted;
+    impl Message for TorRestarted {
+        type Result = ();
+    }
+    pub struct TorEventReceived(pub TorEvent);
+    impl Message for TorEventReceived {
+        type Result = ();
+    }
 }
@@ -10,12 +10,25 @@ use std::{
 pub struct ConnectionStatus {
     pub addr: Option<NodeAddress>,
     pub alive_at: SystemTime,
+    pub capabilities: Option<Vec<i32>>,
+}
+
+#[derive(Clone, Default)]
+pub struct TorHealth {
+    pub bootstrap_progress: u8,
+    pub circuits_built: u32,
+    pub circuits_failed: u32,
+    pub last_circuit_failure: Option<String>,
+    pub descriptor_published: bool,
+    pub descriptor_uploads: u32,
+    pub descriptor_upload_failures: u32,
 }
 
 #[derive(Clone)]
 pub struct Status {
     bootstrap_state: Arc<RwLock<BootstrapState>>,
     connections: Arc<RwLock<HashMap<ConnectionId, ConnectionStatus>>>,
+    tor_health: Arc<RwLock<Option<TorHealth>>>,
 }
 
 impl Status {
@@ -23,13 +36,26 @@ impl Status {
         Self {
             bootstrap_state,
             connections: Arc::new(RwLock::new(HashMap::new())),
+            tor_health: Arc::new(RwLock::new(None)),
         }
     }
     pub fn bootstrap_state(&self) -> BootstrapState {
-        *self
-            .bootstrap_state
+        self.bootstrap_state
             .read()
             .expect("Corrupted lock in status")
+            .clone()
+    }
+
+    pub fn tor_health(&self) -> Option<TorHealth> {
+        self.tor_health
+            .read()
+            .expect("Corrupted lock in status")
+            .clone()
+    }
+
+    pub fn update_tor_health(&mut self, update: impl FnOnce(&mut TorHealth)) {
+        let mut tor_health = self.tor_health.write().expect("Corrupted lock in status");
+        update(tor_health.get_or_insert_with(TorHealth::default))
     }
 
     pub fn connections(&self) -> RwLockReadGuard<HashMap<ConnectionId, ConnectionStatus>> {
@@ -45,6 +71,7 @@ impl Status {
                 ConnectionStatus {
                     addr,
                     alive_at: SystemTime::now(),
+                    capabilities: None,
                 },
             );
     }
@@ -65,6 +92,17 @@ impl Status {
         status.alive_at = SystemTime::now();
     }
 
+    pub fn connection_capabilities(&mut self, id: &ConnectionId, capabilities: Vec<i32>) {
+        if let Some(status) = self
+            .connections
+            .write()
+            .expect("Corrupted lock in status")
+            .get_mut(id)
+        {
+            status.capabilities = Some(capabilities);
+        }
+    }
+
     pub fn connection_alive(&mut self, id: &ConnectionId, at: SystemTime) {
         self.connections
             .write()
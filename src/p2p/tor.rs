@@ -1,3 +1,7 @@
+mod process;
+
+pub use process::{TorProcess, TorProcessConfig};
+
 use crate::prelude::{
     hmac::{Hmac, HmacEngine},
     sha256, FromHex, Hash, HashEngine, ToHex,
@@ -16,6 +20,10 @@ use std::{
 const PROTOCOL_INFO_VERSION: i32 = 1;
 const COOKIE_LENGTH: usize = 32;
 const NONCE_LENGTH: usize = 32;
+const ASYNC_EVENT_STATUS: u32 = 650;
+const V3_KEY_TYPE: &str = "ED25519-V3";
+const V3_KEY_PREFIX: &str = "ED25519-V3:";
+const V3_SERVICE_ID_LENGTH: usize = 56;
 static SERVER_KEY: &[u8; 56] = b"Tor safe cookie authentication server-to-controller hash";
 static CONTROLLER_KEY: &[u8; 56] = b"Tor safe cookie authentication controller-to-server hash";
 
@@ -42,6 +50,10 @@ pub enum TCError {
     UnknownResponse,
     CannotReadAuthCookie,
     AuthenticationError,
+    PasswordRequired,
+    UnsupportedAuthMethods,
+    BootstrapTimeout,
+    TorExited,
     TorError(TCErrorKind),
 }
 
@@ -92,6 +104,16 @@ pub struct OnionAddr {
     pub onion_service: String,
 }
 
+#[derive(Debug)]
+pub enum TorEvent {
+    BootstrapProgress(u8),
+    DescriptorUploaded(String),
+    DescriptorUploadFailed(String, String),
+    CircuitBuilt,
+    CircuitFailed(String),
+    Other,
+}
+
 #[derive(Debug)]
 struct ProtocolInfo {
     cookiefile: String,
@@ -100,14 +122,25 @@ struct ProtocolInfo {
 }
 
 impl TorControl {
-    pub fn connect(addr: impl ToSocketAddrs) -> TCResult<Self> {
-        TorControl(BufStream::new(TcpStream::connect(addr)?)).authenticate()
+    pub fn connect(addr: impl ToSocketAddrs, password: Option<&str>) -> TCResult<Self> {
+        TorControl(BufStream::new(TcpStream::connect(addr)?)).authenticate(password)
     }
 
-    pub fn add_v2_onion(&mut self, conf: AddOnionConfig) -> TCResult<OnionAddr> {
+    // Creates an ED25519-V3 hidden service. A persisted RSA1024 (v2) key is
+    // moved aside and replaced, since tor no longer supports v2 services.
+    pub fn add_onion(&mut self, conf: AddOnionConfig) -> TCResult<OnionAddr> {
         let key_param = match fs::read_to_string(&conf.private_key_path) {
-            Ok(key) => key,
-            _ => "NEW:RSA1024".into(),
+            Ok(ref key) if key.trim().starts_with(V3_KEY_PREFIX) => key.trim().to_string(),
+            Ok(_) => {
+                let backup_path = conf.private_key_path.with_extension("v2.key");
+                warn!(
+                    "Migrating hidden service to v3, the old key is kept at {}",
+                    backup_path.display()
+                );
+                fs::rename(&conf.private_key_path, backup_path)?;
+                format!("NEW:{}", V3_KEY_TYPE)
+            }
+            Err(_) => format!("NEW:{}", V3_KEY_TYPE),
         };
         let port_param = format!("Port={},{}", conf.virtual_port, conf.target_port);
         send_command(
@@ -118,7 +151,7 @@ impl TorControl {
         let mut service_id = "";
         let mut private_key = "";
         for section in response.split(' ') {
-            let split: Vec<&str> = section.split('=').collect();
+            let split: Vec<&str> = section.splitn(2, '=').collect();
             if split.len() == 2 {
                 match split[0] {
                     "ServiceID" => service_id = split[1],
@@ -127,6 +160,9 @@ impl TorControl {
                 }
             }
         }
+        if service_id.len() != V3_SERVICE_ID_LENGTH {
+            return Err(TCError::UnknownResponse);
+        }
         if private_key != "" {
             let mut key_file = File::create(conf.private_key_path)?;
             key_file.write_all(private_key.as_bytes())?
@@ -137,6 +173,59 @@ impl TorControl {
         })
     }
 
+    pub fn get_info(&mut self, key: &str) -> TCResult<String> {
+        send_command(&mut self.0, format!("GETINFO {}", key))?;
+        let prefix = format!("{}=", key);
+        read_lines(&mut self.0)?
+            .into_iter()
+            .find(|line| line.starts_with(&prefix))
+            .map(|line| line[prefix.len()..].to_string())
+            .ok_or(TCError::UnknownResponse)
+    }
+
+    pub fn bootstrap_progress(&mut self) -> TCResult<u8> {
+        self.get_info("status/bootstrap-phase")?
+            .split(' ')
+            .find(|section| section.starts_with("PROGRESS="))
+            .and_then(|section| section["PROGRESS=".len()..].parse().ok())
+            .ok_or(TCError::UnknownResponse)
+    }
+
+    // Tor exits as soon as this control connection is closed
+    pub fn take_ownership(&mut self) -> TCResult<()> {
+        send_command(&mut self.0, "TAKEOWNERSHIP".to_string())?;
+        read_lines(&mut self.0).map(|_| ())
+    }
+
+    pub fn signal(&mut self, signal: &str) -> TCResult<()> {
+        send_command(&mut self.0, format!("SIGNAL {}", signal))?;
+        read_lines(&mut self.0).map(|_| ())
+    }
+
+    // After subscribing the connection should only be used to read events
+    pub fn set_events(&mut self, events: &[&str]) -> TCResult<()> {
+        send_command(&mut self.0, format!("SETEVENTS {}", events.join(" ")))?;
+        read_lines(&mut self.0).map(|_| ())
+    }
+
+    // Blocks until tor sends the next asynchronous event
+    pub fn next_event(&mut self) -> TCResult<TorEvent> {
+        let mut event = Vec::new();
+        let mut buf = String::new();
+        loop {
+            let (status, end, msg) = parse_line(&mut self.0, &mut buf)?;
+            if status != ASYNC_EVENT_STATUS {
+                return Err(TCError::UnknownResponse);
+            }
+            event.push(msg.trim_end().to_owned());
+            if end {
+                break;
+            }
+            buf.clear();
+        }
+        Ok(parse_event(&event.join(" ")))
+    }
+
     fn protocol_info(&mut self) -> TCResult<ProtocolInfo> {
         send_command(
             &mut self.0,
@@ -147,7 +236,7 @@ impl TorControl {
         let mut auth_methods = "";
         let mut tor_version = "";
         for section in response.split(' ') {
-            let split: Vec<&str> = section.split('=').collect();
+            let split: Vec<&str> = section.splitn(2, '=').collect();
             if split.len() == 2 {
                 match split[0] {
                     "COOKIEFILE" => cookiefile = split[1],
@@ -164,9 +253,30 @@ impl TorControl {
         })
     }
 
-    fn authenticate(mut self) -> TCResult<Self> {
-        let auth_cookie = self.get_auth_cookie()?;
+    fn authenticate(mut self, password: Option<&str>) -> TCResult<Self> {
+        let info = self.protocol_info()?;
+        debug!(
+            "Authenticating with tor {} offering {}",
+            info.tor_version,
+            info.auth_methods.join(",")
+        );
+        let supports = |method: &str| info.auth_methods.iter().any(|m| m == method);
+        let credential = match password {
+            _ if supports("NULL") => String::new(),
+            Some(password) if supports("HASHEDPASSWORD") => <[u8]>::to_hex(password.as_bytes()),
+            _ if supports("SAFECOOKIE") => {
+                let auth_cookie = read_auth_cookie(&info.cookiefile)?;
+                self.safe_cookie_response(auth_cookie)?
+            }
+            _ if supports("COOKIE") => read_auth_cookie(&info.cookiefile)?.to_hex(),
+            _ if supports("HASHEDPASSWORD") => return Err(TCError::PasswordRequired),
+            _ => return Err(TCError::UnsupportedAuthMethods),
+        };
+        send_command(&mut self.0, format!("AUTHENTICATE {}", credential))?;
+        read_lines(&mut self.0).map(|_| self)
+    }
 
+    fn safe_cookie_response(&mut self, auth_cookie: Vec<u8>) -> TCResult<String> {
         let client_nonce = rand::thread_rng().gen::<[u8; NONCE_LENGTH]>();
         send_command(
             &mut self.0,
@@ -186,10 +296,10 @@ impl TorControl {
                 }
             }
         }
-        let decoded_server_hash = FromStr::from_str(serverhash)
-            .expect("Could not decode serverhash during Authentication");
-        let decoded_server_nonce: Vec<u8> = FromHex::from_hex(servernonce)
-            .expect("Could not decode servernonce during Authentication");
+        let decoded_server_hash: Hmac<sha256::Hash> =
+            FromStr::from_str(serverhash).map_err(|_| TCError::AuthenticationError)?;
+        let decoded_server_nonce: Vec<u8> =
+            FromHex::from_hex(servernonce).map_err(|_| TCError::AuthenticationError)?;
 
         let mut message = Vec::new();
         message.extend(auth_cookie);
@@ -205,26 +315,47 @@ impl TorControl {
 
         let mut client_engine = HmacEngine::<sha256::Hash>::new(CONTROLLER_KEY);
         client_engine.input(&message);
-        send_command(
-            &mut self.0,
-            format!(
-                "AUTHENTICATE {}",
-                Hmac::<sha256::Hash>::from_engine(client_engine)
-            ),
-        )?;
+        Ok(Hmac::<sha256::Hash>::from_engine(client_engine).to_string())
+    }
+}
 
-        read_lines(&mut self.0).map(|_| self)
+fn read_auth_cookie(cookiefile: &str) -> TCResult<Vec<u8>> {
+    let mut file_content = Vec::new();
+    let length = File::open(cookiefile)
+        .and_then(|mut file| file.read_to_end(&mut file_content))
+        .map_err(|_| TCError::CannotReadAuthCookie)?;
+    if length != COOKIE_LENGTH {
+        Err(TCError::CannotReadAuthCookie)
+    } else {
+        Ok(file_content)
     }
+}
 
-    fn get_auth_cookie(&mut self) -> TCResult<Vec<u8>> {
-        let info = self.protocol_info()?;
-        let mut file_content = Vec::new();
-        let length = File::open(info.cookiefile)?.read_to_end(&mut file_content)?;
-        if length != COOKIE_LENGTH {
-            Err(TCError::CannotReadAuthCookie)
-        } else {
-            Ok(file_content)
+fn parse_event(event: &str) -> TorEvent {
+    let words: Vec<&str> = event.split(' ').collect();
+    let reason = || {
+        words
+            .iter()
+            .find(|word| word.starts_with("REASON="))
+            .map(|word| word["REASON=".len()..].to_string())
+            .unwrap_or_default()
+    };
+    match (words.get(0), words.get(1), words.get(2)) {
+        (Some(&"STATUS_CLIENT"), _, Some(&"BOOTSTRAP")) => words
+            .iter()
+            .find(|word| word.starts_with("PROGRESS="))
+            .and_then(|word| word["PROGRESS=".len()..].parse().ok())
+            .map(TorEvent::BootstrapProgress)
+            .unwrap_or(TorEvent::Other),
+        (Some(&"HS_DESC"), Some(&"UPLOADED"), Some(service_id)) => {
+            TorEvent::DescriptorUploaded(service_id.to_string())
+        }
+        (Some(&"HS_DESC"), Some(&"FAILED"), Some(service_id)) => {
+            TorEvent::DescriptorUploadFailed(service_id.to_string(), reason())
         }
+        (Some(&"CIRC"), _, Some(&"BUILT")) => TorEvent::CircuitBuilt,
+        (Some(&"CIRC"), _, Some(&"FAILED")) => TorEvent::CircuitFailed(reason()),
+        _ => TorEvent::Other,
     }
 }
 
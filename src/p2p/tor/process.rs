@@ -0,0 +1,186 @@
+use super::{TCError, TCResult, TorControl};
+use crate::{p2p::server::event::TorRestarted, prelude::*};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Child, Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+const TORRC_FILE: &str = "torrc";
+const DATA_DIR: &str = "data";
+const LOG_FILE: &str = "tor.log";
+const CONTROL_PORT_TIMEOUT: Duration = Duration::from_secs(30);
+const BOOTSTRAP_TIMEOUT: Duration = Duration::from_secs(300);
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const SUPERVISE_INTERVAL: Duration = Duration::from_secs(5);
+
+pub struct TorProcessConfig {
+    pub tor_binary: PathBuf,
+    pub tor_dir: PathBuf,
+    pub control_port: u16,
+    pub socks_port: u16,
+}
+
+struct Tor {
+    child: Child,
+    control: TorControl,
+}
+
+// Handle to a tor process launched by risq, clones share the same process
+#[derive(Clone)]
+pub struct TorProcess {
+    config: Arc<TorProcessConfig>,
+    tor: Arc<Mutex<Tor>>,
+    shutting_down: Arc<AtomicBool>,
+}
+
+impl TorProcess {
+    // Blocks until the launched tor has finished bootstrapping
+    pub fn launch(config: TorProcessConfig) -> TCResult<Self> {
+        let tor = spawn(&config)?;
+        Ok(Self {
+            config: Arc::new(config),
+            tor: Arc::new(Mutex::new(tor)),
+            shutting_down: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    // Restarting blocks until tor bootstrapped, so it gets its own thread
+    pub fn supervise(&self, on_restart: Recipient<TorRestarted>) {
+        let process = self.clone();
+        thread::spawn(move || loop {
+            thread::sleep(SUPERVISE_INTERVAL);
+            if process.shutting_down.load(Ordering::SeqCst) {
+                return;
+            }
+            if process.restart_if_exited() {
+                let _ = on_restart.do_send(TorRestarted);
+            }
+        });
+    }
+
+    pub fn shutdown(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+        let mut tor = self.tor.lock().expect("Tor process lock poisoned");
+        stop(&mut tor);
+    }
+
+    // Returns true if tor had exited and was relaunched
+    fn restart_if_exited(&self) -> bool {
+        match self
+            .tor
+            .lock()
+            .expect("Tor process lock poisoned")
+            .child
+            .try_wait()
+        {
+            Ok(None) => return false,
+            Ok(Some(status)) => warn!("Tor exited ({}), restarting", status),
+            Err(e) => warn!("Couldn't check tor process: {:?}, restarting", e),
+        }
+        let mut restarted = match spawn(&self.config) {
+            Ok(restarted) => restarted,
+            Err(e) => {
+                error!("Couldn't restart tor: {:?}", e);
+                return false;
+            }
+        };
+        let mut tor = self.tor.lock().expect("Tor process lock poisoned");
+        // shutdown() ran while the new tor was bootstrapping
+        if self.shutting_down.load(Ordering::SeqCst) {
+            stop(&mut restarted);
+            return false;
+        }
+        *tor = restarted;
+        true
+    }
+}
+
+fn stop(tor: &mut Tor) {
+    if let Ok(None) = tor.child.try_wait() {
+        info!("Shutting down tor");
+        if tor.control.signal("SHUTDOWN").is_err() {
+            let _ = tor.child.kill();
+        }
+        let _ = tor.child.wait();
+    }
+}
+
+fn spawn(config: &TorProcessConfig) -> TCResult<Tor> {
+    let data_dir = config.tor_dir.join(DATA_DIR);
+    fs::create_dir_all(&data_dir)?;
+    let torrc_path = config.tor_dir.join(TORRC_FILE);
+    fs::write(&torrc_path, torrc(config, &data_dir))?;
+
+    info!("Launching {}", config.tor_binary.display());
+    let mut child = Command::new(&config.tor_binary)
+        .arg("-f")
+        .arg(&torrc_path)
+        .stdout(Stdio::null())
+        .spawn()?;
+    match wait_for_bootstrap(config, &mut child) {
+        Ok(control) => Ok(Tor { child, control }),
+        Err(e) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            Err(e)
+        }
+    }
+}
+
+fn wait_for_bootstrap(config: &TorProcessConfig, child: &mut Child) -> TCResult<TorControl> {
+    let started_at = Instant::now();
+    let mut control = loop {
+        if let Ok(Some(_)) = child.try_wait() {
+            return Err(TCError::TorExited);
+        }
+        match TorControl::connect(("127.0.0.1", config.control_port), None) {
+            Ok(control) => break control,
+            Err(TCError::IoError(_)) | Err(TCError::CannotReadAuthCookie)
+                if started_at.elapsed() < CONTROL_PORT_TIMEOUT =>
+            {
+                thread::sleep(POLL_INTERVAL)
+            }
+            Err(e) => return Err(e),
+        }
+    };
+    control.take_ownership()?;
+    loop {
+        let progress = control.bootstrap_progress()?;
+        if progress >= 100 {
+            info!("Tor finished bootstrapping");
+            return Ok(control);
+        }
+        if started_at.elapsed() > BOOTSTRAP_TIMEOUT {
+            return Err(TCError::BootstrapTimeout);
+        }
+        debug!("Tor bootstrapping: {}%", progress);
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn torrc(config: &TorProcessConfig, data_dir: &Path) -> String {
+    format!(
+        "DataDirectory {}
+Log notice file {}
+ControlPort {}
+CookieAuthentication 1
+SOCKSPort {}
+SafeSocks 0
+HiddenServiceStatistics 0
+AvoidDiskWrites 1
+__OwningControllerProcess {}
+",
+        data_dir.display(),
+        config.tor_dir.join(LOG_FILE).display(),
+        config.control_port,
+        config.socks_port,
+        process::id()
+    )
+}
@@ -15,7 +15,11 @@ test_tmp_dir() {
 
 start_dummy_seed() {
   echo ${1} > $(test_tmp_dir)/dummy_seed_port
-  background "${risq}" dummy-seed -p ${1} > $(test_tmp_dir)/dummy_seed_pid
+  local fixtures
+  if [ -n "${2}" ]; then
+    fixtures="-f ${BATS_TEST_DIRNAME}/fixtures/${2}"
+  fi
+  background "${risq}" dummy-seed -p ${1} ${fixtures} > $(test_tmp_dir)/dummy_seed_pid
 }
 
 seed_addr() {
@@ -25,7 +29,7 @@ seed_addr() {
 start_node() {
   echo ${2} > $(test_tmp_dir)/${1}_port
   echo ${3} > $(test_tmp_dir)/${1}_api_port
-  background "${risq}" d --api-port ${3} -p ${2} -n BtcRegtest \
+  RISQ_HOME=$(test_tmp_dir)/${1} background "${risq}" d --api-port ${3} -p ${2} -n BtcRegtest \
           --force-seed $(seed_addr) --no-tor > $(test_tmp_dir)/${1}_pid
 }
 
@@ -34,6 +38,13 @@ node_status() {
   curl -s "127.0.0.1:${api_port}/status" | jq -r "${2}"
 }
 
+node_query() {
+  api_port=$(cat $(test_tmp_dir)/${1}_api_port)
+  curl -s -XPOST -H "Content-Type: application/json" \
+       --data "{ \"query\": \"${2}\" }" \
+       "127.0.0.1:${api_port}/graphql" | jq -r "${3}"
+}
+
 node_addr() {
   echo "127.0.0.1:$(cat $(test_tmp_dir)/${1}_port)"
 }
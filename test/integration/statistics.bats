@@ -0,0 +1,20 @@
+#!/usr/bin/env bats
+
+load "helpers"
+
+setup() {
+  start_dummy_seed $(expr 10100 + ${BATS_TEST_NUMBER}) "trade_statistics.json"
+}
+
+teardown() {
+  stop_dummy_seed
+}
+
+@test "alice receives trade statistics from the seed" {
+  start_node "alice" 3101 3111
+
+  retry 10 1 [ "$(node_query alice '{ trades { offerId } }' '.data.trades | length')" == "3" ]
+  [ "$(node_query alice '{ trades { offerId } }' '.data.trades[0].offerId')" == "fixture-offer-3" ]
+
+  stop_node "alice"
+}